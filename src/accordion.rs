@@ -0,0 +1,81 @@
+use crate::collapse::Collapse;
+use leptos::*;
+use std::collections::HashSet;
+
+/// Shared between [`Accordion`] and each [`AccordionSection`] inside it.
+#[derive(Clone, Copy)]
+struct AccordionContext {
+    /// How many sections have been created so far, used to give each one
+    /// an index.
+    count: StoredValue<usize>,
+    open: RwSignal<HashSet<usize>>,
+    allow_multiple: bool,
+}
+
+/// A list of [`AccordionSection`]s that can each be expanded or collapsed.
+#[component]
+pub fn Accordion(
+    cx: Scope,
+    #[prop(default = false)]
+    /// Whether more than one section can be open at once. If not, opening a
+    /// section closes the others.
+    allow_multiple: bool,
+    children: Children,
+) -> impl IntoView {
+    provide_context(
+        cx,
+        AccordionContext {
+            count: store_value(cx, 0),
+            open: create_rw_signal(cx, HashSet::new()),
+            allow_multiple,
+        },
+    );
+
+    view! { cx,
+        <div class="accordion">{children(cx)}</div>
+    }
+}
+
+/// A titled section of an [`Accordion`].
+#[component]
+pub fn AccordionSection(
+    cx: Scope,
+    /// The text of the button that expands this section.
+    #[prop(into)]
+    title: String,
+    children: Children,
+) -> impl IntoView {
+    let AccordionContext {
+        count,
+        open,
+        allow_multiple,
+    } = use_context(cx).expect("`AccordionSection` to be used inside `Accordion`");
+    let index = count.get_value();
+    count.set_value(index + 1);
+
+    let is_open = move || open.with(|open| open.contains(&index));
+    let toggle = move |_| {
+        open.update(|open| {
+            if !open.remove(&index) {
+                if !allow_multiple {
+                    open.clear();
+                }
+                open.insert(index);
+            }
+        })
+    };
+
+    view! { cx,
+        <div class="accordion-section">
+            <button
+                aria-expanded=move || is_open().to_string()
+                on:click=toggle
+            >
+                {title}
+            </button>
+            <Collapse open=Signal::derive(cx, is_open)>
+                {children(cx)}
+            </Collapse>
+        </div>
+    }
+}
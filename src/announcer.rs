@@ -0,0 +1,56 @@
+use crate::reactive_utils::debounce;
+use leptos::*;
+
+/// How long, in milliseconds, announcements have to stop coming before the
+/// latest one is read out.
+const ANNOUNCE_DELAY: i32 = 500;
+
+/// Lets any component tell screen reader users about something that
+/// changed on the page, without moving their focus.
+///
+/// ```ignore
+/// let announcer = expect_context::<Announcer>(cx);
+/// announcer.announce("Counter added");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Announcer {
+    message: ReadSignal<String>,
+    set_message: WriteSignal<String>,
+}
+
+impl Announcer {
+    /// Has screen readers read out `text`, once they've finished what
+    /// they're saying. If several are made close together, only the last
+    /// is read out.
+    pub fn announce(&self, text: impl Into<String>) {
+        self.set_message.set(text.into());
+    }
+}
+
+/// Creates an [`Announcer`] and provides it to every component below `cx`.
+/// A [`LiveRegion`] should be rendered somewhere below it too.
+pub fn provide_announcer(cx: Scope) -> Announcer {
+    let (message, set_message) = create_signal(cx, String::new());
+    let announcer = Announcer {
+        message,
+        set_message,
+    };
+    provide_context(cx, announcer);
+    announcer
+}
+
+/// Where the [`Announcer`]'s messages go. It's hidden, but screen readers
+/// read out any change to the text in an `aria-live` region.
+#[component]
+pub fn LiveRegion(cx: Scope) -> impl IntoView {
+    let announcer = expect_context::<Announcer>(cx);
+    // Otherwise, e.g., clicking "Add Counter" five times quickly would have
+    // the screen reader read out all five.
+    let message = debounce(cx, announcer.message, ANNOUNCE_DELAY);
+
+    view! { cx,
+        <div class="visually-hidden" aria-live="polite" aria-atomic="true">
+            {message}
+        </div>
+    }
+}
@@ -0,0 +1,62 @@
+use leptos::{html::Textarea, *};
+
+/// The tallest, in pixels, that an [`AutoTextarea`] grows to. Past this, it
+/// scrolls instead.
+const MAX_HEIGHT: i32 = 240;
+
+/// A `<textarea>` that's bound to `value` in both directions, and grows or
+/// shrinks to fit what's typed into it.
+#[component]
+pub fn AutoTextarea(
+    cx: Scope,
+    /// The text in the textarea.
+    value: RwSignal<String>,
+    #[prop(optional, into)]
+    /// Shown while the textarea is empty.
+    placeholder: Option<String>,
+) -> impl IntoView {
+    let textarea: NodeRef<Textarea> = create_node_ref(cx);
+
+    // `scrollHeight` is how tall the content is, but it never goes below
+    // the current height, so the height is reset first to let it shrink.
+    let resize = move || {
+        let Some(textarea) = textarea.get_untracked() else {
+            return;
+        };
+        let style = (**textarea).style();
+        _ = style.set_property("height", "auto");
+        let content_height = textarea.scroll_height();
+        _ = style.set_property("height", &format!("{}px", content_height.min(MAX_HEIGHT)));
+        let overflow = if content_height > MAX_HEIGHT {
+            "auto"
+        } else {
+            "hidden"
+        };
+        _ = style.set_property("overflow-y", overflow);
+    };
+
+    // This also runs once at the start, so an initial value is sized too.
+    // It waits a frame because until then the textarea might not be on the
+    // page yet, and so has no height to measure. Changes to `value` from
+    // outside, e.g., clearing it, resize it the same way.
+    create_effect(cx, move |_| {
+        value.track();
+        request_animation_frame(resize);
+    });
+
+    view! { cx,
+        <textarea
+            class="auto-textarea"
+            rows="1"
+            placeholder=placeholder
+            node_ref=textarea
+            prop:value=move || value.get()
+            on:input=move |ev| {
+                value.set(event_target_value(&ev));
+                // Resizing straight away, rather than waiting for the
+                // effect, stops a scrollbar flashing up for a frame.
+                resize();
+            }
+        />
+    }
+}
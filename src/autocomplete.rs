@@ -0,0 +1,126 @@
+use leptos::{ev::KeyboardEvent, *};
+
+/// A text input that suggests matching `options` as the user types.
+///
+/// The arrow keys move through the suggestions, Enter picks the highlighted
+/// one and Escape hides them. Suggestions can also be picked with the mouse.
+#[component]
+pub fn Autocomplete(
+    cx: Scope,
+    /// Everything that can be suggested.
+    options: Vec<String>,
+) -> impl IntoView {
+    let options = store_value(cx, options);
+    let (query, set_query) = create_signal(cx, String::new());
+    let (open, set_open) = create_signal(cx, false);
+    let (highlighted, set_highlighted) = create_signal(cx, None::<usize>);
+
+    let matches = create_memo(cx, move |_| {
+        let query = query.get().to_lowercase();
+        options.with_value(|options| {
+            options
+                .iter()
+                .filter(|option| option.to_lowercase().contains(&query))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let select = move |option: String| {
+        set_query.set(option);
+        set_open.set(false);
+        set_highlighted.set(None);
+    };
+
+    let on_input = move |ev| {
+        set_query.set(event_target_value(&ev));
+        set_open.set(true);
+        set_highlighted.set(None);
+    };
+    let on_keydown = move |ev: KeyboardEvent| {
+        let len = matches.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" => {
+                ev.prevent_default();
+                set_open.set(true);
+                if len > 0 {
+                    set_highlighted.update(|h| *h = Some(h.map_or(0, |h| (h + 1).min(len - 1))));
+                }
+            }
+            "ArrowUp" => {
+                ev.prevent_default();
+                // Moving up from the first suggestion goes back to the input.
+                set_highlighted.update(|h| *h = h.and_then(|h| h.checked_sub(1)));
+            }
+            "Enter" => {
+                let option = highlighted
+                    .get_untracked()
+                    .and_then(|h| matches.with_untracked(|matches| matches.get(h).cloned()));
+                if let Some(option) = option {
+                    ev.prevent_default();
+                    select(option);
+                }
+            }
+            "Escape" => {
+                set_open.set(false);
+                set_highlighted.set(None);
+            }
+            _ => {}
+        }
+    };
+
+    let suggestions = move || {
+        matches.with(|matches| {
+            if matches.is_empty() {
+                return view! { cx,
+                    <li class="disabled" aria-disabled="true">"No results"</li>
+                }
+                .into_view(cx);
+            }
+            matches
+                .iter()
+                .cloned()
+                .enumerate()
+                .map(|(index, option)| {
+                    let is_highlighted = move || highlighted.get() == Some(index);
+                    view! { cx,
+                        <li
+                            role="option"
+                            aria-selected=move || is_highlighted().to_string()
+                            class:highlighted=is_highlighted
+                            // `mousedown` fires before the input loses focus,
+                            // which would otherwise hide the list first.
+                            on:mousedown={
+                                let option = option.clone();
+                                move |_| select(option.clone())
+                            }
+                        >
+                            {option}
+                        </li>
+                    }
+                })
+                .collect_view(cx)
+        })
+    };
+
+    view! { cx,
+        <div class="autocomplete">
+            <input
+                type="text"
+                role="combobox"
+                aria-autocomplete="list"
+                aria-expanded=move || open.get().to_string()
+                prop:value=move || query.get()
+                on:input=on_input
+                on:keydown=on_keydown
+                on:focus=move |_| set_open.set(true)
+                on:blur=move |_| set_open.set(false)
+            />
+            <Show when=move || open.get() fallback=|_| ()>
+                <ul class="autocomplete-list" role="listbox">
+                    {suggestions}
+                </ul>
+            </Show>
+        </div>
+    }
+}
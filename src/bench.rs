@@ -0,0 +1,142 @@
+//! Times how long a keyed list of counters, like the one in `DynamicList`,
+//! takes to add, update, reorder and remove rows. It's only built with the `bench`
+//! feature (`trunk serve --features bench`), which shows it in place of
+//! the demos.
+//!
+//! Leptos updates the DOM as soon as a signal is set, so each timing
+//! includes re-rendering. The operations are the same on every run, so
+//! results can be compared between runs and between changes to the code.
+
+use crate::lists::List;
+use leptos::*;
+
+/// How many times each operation is done, unless it's changed on the page.
+const DEFAULT_OPERATIONS: usize = 1000;
+
+/// The average time of one operation, from a single run.
+#[derive(Clone)]
+struct Timing {
+    operation: &'static str,
+    average_ms: f64,
+}
+
+/// Milliseconds since the page loaded, to a fraction of a millisecond.
+fn now() -> f64 {
+    window()
+        .performance()
+        .expect("`performance` to be available")
+        .now()
+}
+
+/// Runs `f` once for each of `0..n`, and returns the average time it took.
+fn time_each(n: usize, mut f: impl FnMut(usize)) -> f64 {
+    let start = now();
+    for i in 0..n {
+        f(i);
+    }
+    (now() - start) / n.max(1) as f64
+}
+
+#[component]
+pub fn Bench(cx: Scope) -> impl IntoView {
+    let (operations, set_operations) = create_signal(cx, DEFAULT_OPERATIONS);
+    let (counters, set_counters) = create_signal(cx, Vec::<(usize, RwSignal<usize>)>::new());
+    let (timings, set_timings) = create_signal(cx, Vec::<Timing>::new());
+
+    let run = move |_| {
+        let n = operations.get_untracked();
+        set_counters.set(Vec::new());
+
+        // The counters' signals belong to a child scope that's disposed
+        // once they've all been removed, so repeated runs don't keep
+        // every run's signals alive for as long as the page is open.
+        let ((add, update, reorder, remove), disposer) = cx.run_child_scope(|cx| {
+            // Each operation is its own update, so that `<For>` has to diff
+            // the list every time, as it would after a click in
+            // `DynamicList`.
+            let add = time_each(n, |id| {
+                let counter = (id, create_rw_signal(cx, 0));
+                set_counters.update(|counters| counters.push(counter));
+            });
+            let update = time_each(n, |id| {
+                counters.with_untracked(|counters| counters[id].1.update(|n| *n += 1));
+            });
+            // Swapping a row near the front with one near the back moves
+            // two rows without changing any of their keys.
+            let reorder = time_each(n, |i| {
+                set_counters.update(|counters| counters.swap(i, n - 1 - i));
+            });
+            // Removing from the front moves every row after it, which is
+            // the slowest case for keyed diffing.
+            let remove = time_each(n, |_| {
+                set_counters.update(|counters| {
+                    counters.remove(0);
+                });
+            });
+            (add, update, reorder, remove)
+        });
+        disposer.dispose();
+
+        let results = vec![
+            Timing {
+                operation: "add",
+                average_ms: add,
+            },
+            Timing {
+                operation: "update",
+                average_ms: update,
+            },
+            Timing {
+                operation: "reorder",
+                average_ms: reorder,
+            },
+            Timing {
+                operation: "remove",
+                average_ms: remove,
+            },
+        ];
+        for timing in &results {
+            log!(
+                "{}: {:.4} ms per operation ({n} operations)",
+                timing.operation,
+                timing.average_ms
+            );
+        }
+        set_timings.set(results);
+    };
+
+    view! { cx,
+        <h1>"Benchmark"</h1>
+        <label>
+            "Operations "
+            <input
+                type="number"
+                min="1"
+                prop:value=move || operations.get().to_string()
+                on:change=move |ev| {
+                    if let Ok(n) = event_target_value(&ev).parse::<usize>() {
+                        set_operations.set(n.max(1));
+                    }
+                }
+            />
+        </label>
+        " "
+        <button on:click=run>"Run"</button>
+        <ul>
+            {move || {
+                timings
+                    .get()
+                    .into_iter()
+                    .map(|timing| view! { cx,
+                        <li>{timing.operation} ": " {format!("{:.4}", timing.average_ms)} " ms"</li>
+                    })
+                    .collect_view(cx)
+            }}
+        </ul>
+        <List
+            items=counters
+            key=|counter| counter.0
+            view=|_, (_, count)| count
+        />
+    }
+}
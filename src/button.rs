@@ -0,0 +1,74 @@
+use crate::callback::Callback;
+use leptos::*;
+
+/// A `<button>` that can be disabled, or show that the action it started
+/// is still in progress.
+///
+/// While `loading`, it shows a spinner and is disabled, so the action
+/// can't be started twice.
+#[component]
+pub fn Button(
+    cx: Scope,
+    #[prop(into)]
+    /// Called when the button is clicked.
+    on_click: Callback<()>,
+    #[prop(optional, into)]
+    /// Whether the action is in progress.
+    loading: MaybeSignal<bool>,
+    #[prop(optional, into)]
+    /// Whether the button can be clicked.
+    disabled: MaybeSignal<bool>,
+    #[prop(optional, into)]
+    /// Classes for the `<button>`, e.g., to theme it. They can change, e.g.,
+    /// to mark the button as active.
+    class: MaybeSignal<String>,
+    #[prop(optional, into)]
+    /// Inline styles for the `<button>`.
+    style: Option<String>,
+    children: Children,
+) -> impl IntoView {
+    view! { cx,
+        <button
+            // Buttons inside a `<form>` submit it by default.
+            type="button"
+            // Left out while empty, rather than rendered as `class=""`.
+            class=move || Some(class.get()).filter(|class| !class.is_empty())
+            style=style
+            on:click=move |_| on_click.call(())
+            disabled=move || disabled.get() || loading.get()
+            aria-busy=move || loading.get().to_string()
+        >
+            <Show when=move || loading.get() fallback=|_| ()>
+                <span class="spinner" aria-hidden="true"></span>
+            </Show>
+            {children(cx)}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+
+    #[test]
+    fn button_renders_its_class_and_style() {
+        let html = render_html(|cx| {
+            view! { cx,
+                <Button on_click=|_| () class="danger" style="width: 10em">"Delete"</Button>
+            }
+        });
+        assert!(html.contains(r#"class="danger""#), "{html}");
+        assert!(html.contains(r#"style="width: 10em""#), "{html}");
+    }
+
+    #[test]
+    fn button_leaves_out_an_empty_class() {
+        let html = render_html(|cx| {
+            view! { cx,
+                <Button on_click=|_| () class=Signal::derive(cx, String::new)>"Delete"</Button>
+            }
+        });
+        assert!(!html.contains("class"), "{html}");
+    }
+}
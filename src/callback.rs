@@ -0,0 +1,23 @@
+use std::rc::Rc;
+
+/// A callback that can be passed to a component as a prop.
+///
+/// Closures can't be copied into several event handlers, so this wraps
+/// one in an `Rc` to make it cheap to clone.
+#[derive(Clone)]
+pub struct Callback<T>(Rc<dyn Fn(T)>);
+
+impl<T> Callback<T> {
+    pub fn call(&self, value: T) {
+        (self.0)(value)
+    }
+}
+
+impl<T, F> From<F> for Callback<T>
+where
+    F: Fn(T) + 'static,
+{
+    fn from(f: F) -> Self {
+        Self(Rc::new(f))
+    }
+}
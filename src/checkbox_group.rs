@@ -0,0 +1,150 @@
+use leptos::*;
+
+/// A checkbox for each of `options`, bound to the list of `selected` ones,
+/// plus a checkbox that selects all or none of them.
+///
+/// Options are keyed by their index, so two options with the same label
+/// are still separate checkboxes, and a label is in `selected` once for
+/// each of its checkboxes that's checked.
+#[component]
+pub fn CheckboxGroup(
+    cx: Scope,
+    /// The label for each checkbox.
+    options: Vec<String>,
+    /// The labels of the checked options, in the order they were checked.
+    selected: RwSignal<Vec<String>>,
+) -> impl IntoView {
+    let count = options.len();
+    // Stored so that the closures below can share it and still be `Copy`.
+    let all_options = store_value(cx, options.clone());
+    // The indexes of the checked options, in the order they were checked.
+    // `selected` always holds their labels.
+    let checked = create_rw_signal(
+        cx,
+        all_options.with_value(|options| selected.with_untracked(|s| indexes_for(options, s))),
+    );
+    let set_checked = move |indexes: Vec<usize>| {
+        selected.set(all_options.with_value(|options| labels_at(options, &indexes)));
+        checked.set(indexes);
+    };
+    // Follows changes made to `selected` from outside. Changes made here
+    // already match, and are left alone, so a checked duplicate doesn't
+    // jump to the first option with its label.
+    create_effect(cx, move |_| {
+        selected.with(|selected| {
+            let labels = all_options.with_value(|options| {
+                checked.with_untracked(|checked| labels_at(options, checked))
+            });
+            if labels != *selected {
+                checked.set(all_options.with_value(|options| indexes_for(options, selected)));
+            }
+        });
+    });
+
+    let is_checked = move |index: usize| checked.with(|checked| checked.contains(&index));
+    let all_selected = move || checked.with(|checked| checked.len() == count);
+    let none_selected = move || checked.with(Vec::is_empty);
+    let toggle_all = move |_| {
+        if all_selected() {
+            set_checked(Vec::new());
+        } else {
+            set_checked((0..count).collect());
+        }
+    };
+
+    // The options never change, so there's no need for `<For>`, and each
+    // row is simply rendered in order.
+    let checkboxes = options
+        .into_iter()
+        .enumerate()
+        .map(|(index, label)| {
+            let on_change = move |ev| {
+                let mut indexes = checked.get_untracked();
+                if event_target_checked(&ev) {
+                    if !indexes.contains(&index) {
+                        indexes.push(index);
+                    }
+                } else {
+                    indexes.retain(|checked| *checked != index);
+                }
+                set_checked(indexes);
+            };
+            view! { cx,
+                <li>
+                    <label>
+                        <input
+                            type="checkbox"
+                            prop:checked=move || is_checked(index)
+                            on:change=on_change
+                        />
+                        {label}
+                    </label>
+                </li>
+            }
+        })
+        .collect_view(cx);
+
+    view! { cx,
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=all_selected
+                // Shown as a dash when only some are checked.
+                prop:indeterminate=move || !all_selected() && !none_selected()
+                on:change=toggle_all
+            />
+            "Select all"
+        </label>
+        <ul>{checkboxes}</ul>
+    }
+}
+
+/// The labels of the options at `indexes`, in the same order.
+fn labels_at(options: &[String], indexes: &[usize]) -> Vec<String> {
+    indexes
+        .iter()
+        .filter_map(|&index| options.get(index).cloned())
+        .collect()
+}
+
+/// The index of an option for each of `labels`, in the same order. Each
+/// label uses the first option with that label that isn't used yet, and
+/// labels without one are left out.
+fn indexes_for(options: &[String], labels: &[String]) -> Vec<usize> {
+    let mut indexes = Vec::new();
+    for label in labels {
+        if let Some(index) =
+            (0..options.len()).find(|index| !indexes.contains(index) && options[*index] == *label)
+        {
+            indexes.push(index);
+        }
+    }
+    indexes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(strings: &[&str]) -> Vec<String> {
+        strings.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn indexes_for_gives_duplicate_labels_separate_options() {
+        let options = strings(&["Cheese", "Olives", "Cheese"]);
+        assert_eq!(indexes_for(&options, &strings(&["Cheese"])), [0]);
+        assert_eq!(
+            indexes_for(&options, &strings(&["Cheese", "Cheese"])),
+            [0, 2]
+        );
+        assert_eq!(labels_at(&options, &[2, 1]), strings(&["Cheese", "Olives"]));
+    }
+
+    #[test]
+    fn indexes_for_leaves_out_labels_without_an_option() {
+        let options = strings(&["Cheese", "Olives"]);
+        let labels = strings(&["Ham", "Olives", "Olives"]);
+        assert_eq!(indexes_for(&options, &labels), [1]);
+    }
+}
@@ -0,0 +1,72 @@
+use leptos::*;
+use std::time::Duration;
+use wasm_bindgen_futures::JsFuture;
+
+/// How long the result of copying is shown before the button reverts.
+const FEEDBACK_DURATION: Duration = Duration::from_millis(1500);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CopyState {
+    Idle,
+    Copied,
+    Failed,
+}
+
+/// Writes `text` to the clipboard.
+async fn write_to_clipboard(text: &str) -> Result<(), ()> {
+    let clipboard = window().navigator().clipboard().ok_or(())?;
+    JsFuture::from(clipboard.write_text(text))
+        .await
+        .map(|_| ())
+        .map_err(|_| ())
+}
+
+/// A button that copies `text` to the clipboard, briefly showing whether
+/// it worked.
+#[component]
+pub fn CopyButton(
+    cx: Scope,
+    /// What to copy. This is read when the button is clicked.
+    #[prop(into)]
+    text: Signal<String>,
+) -> impl IntoView {
+    let (state, set_state) = create_signal(cx, CopyState::Idle);
+    let revert = store_value(cx, None::<TimeoutHandle>);
+
+    let clear_revert = move || {
+        if let Some(handle) = revert.get_value() {
+            handle.clear();
+            revert.set_value(None);
+        }
+    };
+    on_cleanup(cx, clear_revert);
+
+    let on_click = move |_| {
+        let text = text.get_untracked();
+        spawn_local(async move {
+            // Writing can fail if the page isn't allowed to use the
+            // clipboard, in which case the browser rejects the promise.
+            let result = write_to_clipboard(&text).await;
+            set_state.set(if result.is_ok() {
+                CopyState::Copied
+            } else {
+                CopyState::Failed
+            });
+            clear_revert();
+            let handle =
+                set_timeout_with_handle(move || set_state.set(CopyState::Idle), FEEDBACK_DURATION)
+                    .ok();
+            revert.set_value(handle);
+        });
+    };
+
+    view! { cx,
+        <button type="button" on:click=on_click>
+            {move || match state.get() {
+                CopyState::Idle => "Copy",
+                CopyState::Copied => "Copied!",
+                CopyState::Failed => "Failed",
+            }}
+        </button>
+    }
+}
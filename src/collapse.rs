@@ -0,0 +1,87 @@
+use leptos::{ev::TransitionEvent, html::Div, *};
+
+/// Shows its children while `open` is `true`, animating their height as
+/// they open and close.
+///
+/// CSS can't transition to or from `height: auto`, so we measure the
+/// content and animate between that and zero, then switch back to `auto`
+/// once it's open so the content can still change size. While closing,
+/// the children stay visible until the transition ends, and are only
+/// hidden after that. Toggling partway through reverses the animation
+/// from wherever it had got to.
+#[component]
+pub fn Collapse(
+    cx: Scope,
+    #[prop(into)]
+    /// Whether the children are shown.
+    open: Signal<bool>,
+    children: Children,
+) -> impl IntoView {
+    let panel: NodeRef<Div> = create_node_ref(cx);
+    let initially_open = open.get_untracked();
+    let hidden = create_rw_signal(cx, !initially_open);
+    // The height in pixels, or `None` for `auto`.
+    let height = create_rw_signal(cx, (!initially_open).then_some(0.0));
+
+    create_effect(cx, move |was_open: Option<bool>| {
+        let is_open = open.get();
+        // There's nothing to animate on the first run, which happens before
+        // the panel is even in the DOM.
+        if was_open.is_none() || was_open == Some(is_open) {
+            return is_open;
+        }
+        let Some(panel) = panel.get_untracked() else {
+            return is_open;
+        };
+
+        if is_open {
+            hidden.set(false);
+            // Reading a layout property makes the browser apply the styles
+            // we're animating from, rather than skipping straight to the end.
+            let target = f64::from(panel.scroll_height());
+            if target == 0.0 {
+                // Nothing to animate, so `transitionend` would never fire.
+                height.set(None);
+            } else {
+                height.set(Some(target));
+            }
+        } else {
+            let current = f64::from(panel.offset_height());
+            if height.get_untracked().is_none() {
+                // Swap `auto` for the actual height, so there's something to
+                // animate from.
+                height.set(Some(current));
+                _ = panel.offset_height();
+            }
+            height.set(Some(0.0));
+            if current == 0.0 {
+                hidden.set(true);
+            }
+        }
+        is_open
+    });
+
+    let on_transition_end = move |ev: TransitionEvent| {
+        // Transitions inside the children bubble up to here too.
+        if ev.target() != ev.current_target() || ev.property_name() != "height" {
+            return;
+        }
+        if open.get_untracked() {
+            height.set(None);
+        } else {
+            hidden.set(true);
+        }
+    };
+
+    view! { cx,
+        <div
+            class="collapse"
+            class:hidden=move || hidden.get()
+            style=move || height.get().map(|height| format!("height: {height}px"))
+            node_ref=panel
+            on:transitionend=on_transition_end
+        >
+            {children(cx)}
+        </div>
+    }
+}
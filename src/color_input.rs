@@ -0,0 +1,43 @@
+use leptos::*;
+
+/// Whether `value` is a color in the `#rrggbb` form that `<input
+/// type="color">` uses.
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// A color picker that's bound to `value`, a color like `"#4682b4"`, in
+/// both directions.
+#[component]
+pub fn ColorInput(
+    cx: Scope,
+    /// The picked color, as `#rrggbb`. If it's set to anything else from
+    /// outside, the picker keeps showing the last valid color.
+    value: RwSignal<String>,
+) -> impl IntoView {
+    // Browsers only ever report `#rrggbb`, but checking means a value that
+    // isn't a color can't end up in `value` through here.
+    let on_input = move |ev| {
+        let new_value = event_target_value(&ev);
+        if is_hex_color(&new_value) {
+            value.set(new_value);
+        }
+    };
+    // Setting an invalid color would make the picker show black.
+    let valid_value = create_memo(cx, move |prev: Option<&String>| {
+        let value = value.get();
+        if is_hex_color(&value) {
+            value
+        } else {
+            prev.cloned().unwrap_or_else(|| "#000000".to_string())
+        }
+    });
+
+    view! { cx,
+        <input type="color" prop:value=move || valid_value.get() on:input=on_input/>
+        " "
+        <code>{move || valid_value.get()}</code>
+    }
+}
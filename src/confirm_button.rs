@@ -0,0 +1,55 @@
+use crate::callback::Callback;
+use leptos::*;
+use std::time::Duration;
+
+/// How long the button waits for the second click before reverting.
+const CONFIRM_WINDOW: Duration = Duration::from_secs(3);
+
+/// A button for destructive actions, which has to be clicked twice.
+///
+/// The first click changes its text to `confirm_label`, and only a second
+/// click within a few seconds calls `on_confirm`. Otherwise it goes back to
+/// showing `label`.
+#[component]
+pub fn ConfirmButton(
+    cx: Scope,
+    #[prop(into)]
+    /// The text shown before the first click.
+    label: String,
+    #[prop(into)]
+    /// The text shown while waiting for the second click.
+    confirm_label: String,
+    #[prop(into)]
+    /// Called when the button is clicked a second time.
+    on_confirm: Callback<()>,
+) -> impl IntoView {
+    let (confirming, set_confirming) = create_signal(cx, false);
+    let revert = store_value(cx, None::<TimeoutHandle>);
+
+    let clear_revert = move || {
+        if let Some(handle) = revert.get_value() {
+            handle.clear();
+            revert.set_value(None);
+        }
+    };
+    on_cleanup(cx, clear_revert);
+
+    let on_click = move |_| {
+        clear_revert();
+        if confirming.get_untracked() {
+            set_confirming.set(false);
+            on_confirm.call(());
+        } else {
+            set_confirming.set(true);
+            let handle =
+                set_timeout_with_handle(move || set_confirming.set(false), CONFIRM_WINDOW).ok();
+            revert.set_value(handle);
+        }
+    };
+
+    view! { cx,
+        <button type="button" on:click=on_click class:red=move || confirming.get()>
+            {move || if confirming.get() { confirm_label.clone() } else { label.clone() }}
+        </button>
+    }
+}
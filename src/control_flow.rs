@@ -0,0 +1,123 @@
+use crate::{
+    accordion::{Accordion, AccordionSection},
+    modal::Modal,
+    split_pane::{LeftPane, RightPane, SplitPane},
+    timers::Stopwatch,
+    toggle_section::ToggleSection,
+    tooltip::Tooltip,
+};
+use leptos::*;
+
+#[component]
+pub fn AppFour(cx: Scope) -> impl IntoView {
+    let (value, set_value) = create_signal(cx, 0);
+
+    let is_odd = move || value.get() & 1 == 1;
+    let odd_text = move || if is_odd() { Some("How odd!") } else { None };
+
+    let (show_modal, set_show_modal) = create_signal(cx, false);
+    let (show_details, set_show_details) = create_signal(cx, false);
+
+    view! { cx,
+        <h1>"Control Flow"</h1>
+
+        <button on:click=move |_| set_value.update(|n| *n += 1)>
+            "+1"
+        </button>
+        <p>"Value is: " {value}</p>
+        <hr/>
+        <h2><code>"Option<T>"</code></h2>
+        <p>{odd_text}</p>
+        <p>{move || odd_text().map(|text| text.len())}</p>
+
+        <h2>"Conditional Logic"</h2>
+
+        <p>
+            {move || if is_odd() {
+                "Odd"
+            } else {
+                "Even"
+            }}
+        </p>
+
+        <p class:hidden=is_odd>"Appears if even."</p>
+
+        <Show when=is_odd
+            fallback=|cx| view! { cx, <p>"Even steven"</p> }
+        >
+            <p>"Oddment"</p>
+        </Show>
+
+        {move || is_odd().then(|| view! { cx, <p>"Oddity!"</p> })}
+
+        <h2>"Converting between Types"</h2>
+        {move || match is_odd() {
+            true if value.get() == 1 => {
+                view! { cx, <pre>"One"</pre> }.into_any()
+            },
+            false if value.get() == 2 => {
+                view! { cx, <p>"Two"</p> }.into_any()
+            }
+            _ => view! { cx, <textarea>{value.get()}</textarea> }.into_any()
+        }}
+
+        <h2>"Show"</h2>
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=move || show_details.get()
+                on:change=move |ev| set_show_details.set(event_target_checked(&ev))
+            />
+            "Show details"
+        </label>
+        // Unlike `class:hidden`, `<Show>` removes its children from the DOM
+        // when `when` is false, and renders `fallback` in their place.
+        <Show
+            when=move || show_details.get()
+            fallback=|cx| view! { cx, <p>"Details are hidden."</p> }
+        >
+            <p>"Here are the details. The value is " {value} "."</p>
+        </Show>
+
+        // Hiding the stopwatch disposes of it, which also clears its
+        // interval, so it doesn't keep ticking in the background.
+        <ToggleSection title="Stopwatch">
+            <Stopwatch/>
+        </ToggleSection>
+
+        <h2>"Accordion"</h2>
+        <Accordion>
+            <AccordionSection title="What is Leptos?">
+                <p>"A framework for building web apps in Rust."</p>
+            </AccordionSection>
+            <AccordionSection title="What is a signal?">
+                <p>"A piece of reactive state that the UI updates to match."</p>
+            </AccordionSection>
+            <AccordionSection title="What is an effect?">
+                <p>"Code that reruns whenever the signals it reads change."</p>
+            </AccordionSection>
+        </Accordion>
+
+        <h2>"Split Pane"</h2>
+        <SplitPane storage_key="split-pane">
+            <LeftPane slot:left>
+                <pre><code>"{move || if is_odd() { \"Odd\" } else { \"Even\" }}"</code></pre>
+            </LeftPane>
+            <RightPane slot:right>
+                <p>{move || if is_odd() { "Odd" } else { "Even" }}</p>
+                // The pane scrolls, but the tooltip can still spill out of it.
+                <Tooltip text="Whether the value is odd or even">
+                    <button>"What's this?"</button>
+                </Tooltip>
+            </RightPane>
+        </SplitPane>
+
+        <h2>"Modal"</h2>
+        <button on:click=move |_| set_show_modal.set(true)>"Open Modal"</button>
+        <Modal show=show_modal on_close=move |_| set_show_modal.set(false)>
+            <p>"Press Escape, click outside, or use the button to close."</p>
+            <input type="text" placeholder="Focus stays in here"/>
+            <button on:click=move |_| set_show_modal.set(false)>"Close"</button>
+        </Modal>
+    }
+}
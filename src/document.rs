@@ -0,0 +1,25 @@
+use leptos::*;
+
+/// Keeps the page's `<title>` set to the result of `title`.
+///
+/// `title` is read inside an effect, so the title updates whenever a signal
+/// it reads changes. Effects only run in the browser, so this never touches
+/// `document()` when rendering on a server. The previous title comes back
+/// when `cx` is cleaned up, e.g., when navigating to another page.
+pub fn set_document_title(cx: Scope, title: impl Fn() -> String + 'static) {
+    // Saved on the effect's first run, rather than here, so that it's only
+    // read in the browser.
+    let previous = store_value(cx, None::<String>);
+    create_effect(cx, move |_| {
+        let document = document();
+        if previous.with_value(Option::is_none) {
+            previous.set_value(Some(document.title()));
+        }
+        document.set_title(&title());
+    });
+    on_cleanup(cx, move || {
+        if let Some(previous) = previous.get_value() {
+            document().set_title(&previous);
+        }
+    });
+}
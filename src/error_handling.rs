@@ -0,0 +1,87 @@
+use leptos::*;
+use std::num::ParseIntError;
+
+/// A text field that parses what's typed into a number.
+///
+/// Rendering a `Result` shows the value if it's `Ok`, and otherwise passes
+/// the error up to the nearest `<ErrorBoundary>`, which shows its fallback
+/// in place of its children. Here, a bad number only replaces the result,
+/// not the whole page.
+#[component]
+pub fn ParsedNumber(cx: Scope) -> impl IntoView {
+    let (value, set_value) = create_signal::<Result<i32, ParseIntError>>(cx, Ok(0));
+    let on_input = move |ev| set_value.set(event_target_value(&ev).trim().parse::<i32>());
+
+    view! { cx,
+        <input type="text" value="0" on:input=on_input/>
+        <ErrorBoundary
+            fallback=|cx, errors| view! { cx,
+                <div class="red">
+                    <p>"Not a number:"</p>
+                    <ul>
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|(_, error)| view! { cx, <li>{error.to_string()}</li> })
+                                .collect::<Vec<_>>()
+                        }}
+                    </ul>
+                </div>
+            }
+        >
+            <p>"Doubled, that's " {move || value.get().map(|value| value.saturating_mul(2))}</p>
+        </ErrorBoundary>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+
+    #[test]
+    fn parsed_number_starts_out_doubling_zero() {
+        let html = render_html(|cx| view! { cx, <ParsedNumber/> });
+        assert!(html.contains("<p>Doubled, that&#x27;s 0</p>"), "{html}");
+        assert!(!html.contains("Not a number"), "{html}");
+    }
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use crate::test_utils::{mount, type_into};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn a_bad_number_only_replaces_the_result() {
+        let root = mount(|cx| view! { cx, <ParsedNumber/> });
+        let input = root
+            .query_selector("input")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+        let text = || root.text_content().unwrap();
+
+        type_into(&input, "abc");
+        assert!(text().contains("Not a number:"), "{}", text());
+        assert!(
+            text().contains("invalid digit found in string"),
+            "{}",
+            text()
+        );
+        assert!(!text().contains("Doubled"), "{}", text());
+        // The input is outside the boundary, so it's still there to fix.
+        assert!(input.is_connected());
+
+        type_into(&input, "21");
+        assert!(text().contains("Doubled, that's 42"), "{}", text());
+        assert!(!text().contains("Not a number:"), "{}", text());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::reactive_utils::debounce;
+use leptos::*;
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// Listens for `event` on `target`, which is usually `window()` or
+/// `document()`, until `cx` is cleaned up.
+///
+/// Event listeners added with `on:` only hear events on their own element
+/// (or bubbling up from inside it). This is for events that could happen
+/// anywhere on the page, like a key press or the window being resized.
+/// Removing the listener on cleanup means a component that's gone can't
+/// still be called.
+pub fn use_event_listener(
+    cx: Scope,
+    target: web_sys::EventTarget,
+    event: &str,
+    handler: impl FnMut(web_sys::Event) + 'static,
+) {
+    let handler = Closure::<dyn FnMut(web_sys::Event)>::new(handler);
+    _ = target.add_event_listener_with_callback(event, handler.as_ref().unchecked_ref());
+
+    let event = event.to_string();
+    // The closure is kept alive by this cleanup function, and dropped (which
+    // frees it on the JavaScript side) once the listener has been removed.
+    on_cleanup(cx, move || {
+        _ = target.remove_event_listener_with_callback(&event, handler.as_ref().unchecked_ref());
+    });
+}
+
+/// How long, in milliseconds, the window has to stop being resized before
+/// [`use_window_size`] updates.
+const RESIZE_DEBOUNCE: i32 = 100;
+
+/// Returns the `(width, height)` of the window, in CSS pixels.
+///
+/// Dragging the edge of the window fires `resize` many times a second, so
+/// this is debounced to only update once the size settles. There's no
+/// window on the server, where it's always `(0.0, 0.0)`. With the `hydrate`
+/// feature, it starts out as `(0.0, 0.0)` too, so the page matches the HTML
+/// it's taking over, and reads the real size once the page has hydrated.
+pub fn use_window_size(cx: Scope) -> Signal<(f64, f64)> {
+    let current = || {
+        let window = window();
+        let dimension = |value: Result<wasm_bindgen::JsValue, _>| {
+            value
+                .ok()
+                .and_then(|value| value.as_f64())
+                .unwrap_or_default()
+        };
+        (
+            dimension(window.inner_width()),
+            dimension(window.inner_height()),
+        )
+    };
+
+    if cfg!(feature = "ssr") {
+        return Signal::derive(cx, || (0.0, 0.0));
+    }
+    let hydrating = cfg!(feature = "hydrate");
+    let (size, set_size) = create_signal(cx, if hydrating { (0.0, 0.0) } else { current() });
+    if hydrating {
+        // Effects run as soon as they're created, while still hydrating, so
+        // this waits for a microtask before changing the size.
+        create_effect(cx, move |_| {
+            queue_microtask(move || set_size.set(current()))
+        });
+    }
+    use_event_listener(cx, window().into(), "resize", move |_| {
+        set_size.set(current())
+    });
+    debounce(cx, size, RESIZE_DEBOUNCE).into()
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn ping() {
+        let event = web_sys::Event::new("events-test-ping").unwrap();
+        window().dispatch_event(&event).unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    fn use_event_listener_stops_listening_once_the_scope_is_disposed() {
+        let calls = Rc::new(Cell::new(0));
+        let runtime = create_runtime();
+        let scope = create_scope(runtime, {
+            let calls = Rc::clone(&calls);
+            move |cx| {
+                use_event_listener(cx, window().into(), "events-test-ping", move |_| {
+                    calls.set(calls.get() + 1)
+                });
+            }
+        });
+
+        ping();
+        assert_eq!(calls.get(), 1);
+
+        scope.dispose();
+        ping();
+        assert_eq!(calls.get(), 1);
+        runtime.dispose();
+    }
+}
@@ -0,0 +1,51 @@
+use leptos::{ev::Event, *};
+use web_sys::Url;
+
+/// A file input for picking an image, which shows a preview of the image
+/// once one is picked.
+#[component]
+pub fn FilePreview(cx: Scope) -> impl IntoView {
+    // An object URL points at the file on the user's machine, and stays
+    // valid (keeping the file in memory) until it's revoked.
+    let (preview, set_preview) = create_signal(cx, None::<String>);
+    let (error, set_error) = create_signal(cx, None::<String>);
+
+    let revoke = move || {
+        if let Some(url) = preview.get_untracked() {
+            _ = Url::revoke_object_url(&url);
+        }
+    };
+    on_cleanup(cx, revoke);
+
+    let on_change = move |ev: Event| {
+        revoke();
+        set_preview.set(None);
+        set_error.set(None);
+
+        let input: web_sys::HtmlInputElement = event_target(&ev);
+        // Cancelling the file picker leaves the input with no files.
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        // `accept` only filters what the picker shows by default, so other
+        // kinds of files can still be picked.
+        if !file.type_().starts_with("image/") {
+            set_error.set(Some(format!("{} isn't an image", file.name())));
+            return;
+        }
+        match Url::create_object_url_with_blob(&file) {
+            Ok(url) => set_preview.set(Some(url)),
+            Err(_) => set_error.set(Some("Couldn't read the file".to_string())),
+        }
+    };
+
+    view! { cx,
+        <input type="file" accept="image/*" on:change=on_change/>
+        {move || error.get().map(|error| view! { cx, <p class="red">{error}</p> })}
+        {move || {
+            preview
+                .get()
+                .map(|url| view! { cx, <img class="file-preview" src=url alt="Preview of the picked image"/> })
+        }}
+    }
+}
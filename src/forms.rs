@@ -0,0 +1,567 @@
+use crate::{
+    auto_textarea::AutoTextarea,
+    autocomplete::Autocomplete,
+    button::Button,
+    checkbox_group::CheckboxGroup,
+    clipboard::CopyButton,
+    error_handling::ParsedNumber,
+    file_preview::FilePreview,
+    masked_input::MaskedInput,
+    radio_group::RadioGroup,
+    reactive_utils::{debounce, use_debounced_effect},
+    search::{sleep, SearchBox, FRUITS},
+    star_rating::StarRating,
+    toast::{ToastContext, ToastKind},
+    wizard::{Wizard, WizardStep},
+};
+use leptos::{
+    ev::{Event, KeyboardEvent, SubmitEvent},
+    html::Input,
+    *,
+};
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+
+/// Where the auto-save of the name field is up to.
+#[derive(Clone, Copy, PartialEq)]
+enum SaveStatus {
+    /// Nothing has been edited yet.
+    Idle,
+    /// The name has been edited, and is waiting to be (or being) saved.
+    Saving,
+    /// The latest edit has been saved.
+    Saved,
+}
+
+/// How long, in milliseconds, to wait after the last edit before saving.
+const AUTO_SAVE_DELAY: i32 = 800;
+
+/// Pretends to save `source` shortly after it stops changing, and returns
+/// a signal tracking how far along that is.
+///
+/// Each edit goes straight back to `Saving`, and cancels a save that was
+/// still in progress so it can't report `Saved` for an older value.
+fn use_auto_save(cx: Scope, source: ReadSignal<String>) -> ReadSignal<SaveStatus> {
+    let (status, set_status) = create_signal(cx, SaveStatus::Idle);
+    let pending_save = store_value(cx, None::<TimeoutHandle>);
+    let cancel_save = move || {
+        if let Some(handle) = pending_save.get_value() {
+            handle.clear();
+        }
+    };
+
+    create_effect(cx, move |prev: Option<()>| {
+        source.track();
+        if prev.is_some() {
+            cancel_save();
+            set_status.set(SaveStatus::Saving);
+        }
+    });
+    use_debounced_effect(
+        cx,
+        move || source.track(),
+        AUTO_SAVE_DELAY,
+        move || {
+            // A real app would send the value to a server here.
+            let handle = set_timeout_with_handle(
+                move || set_status.set(SaveStatus::Saved),
+                Duration::from_millis(500),
+            )
+            .ok();
+            pending_save.set_value(handle);
+        },
+    );
+
+    on_cleanup(cx, cancel_save);
+
+    status
+}
+
+/// How many earlier versions of the name `UndoHistory` keeps.
+const MAX_HISTORY: usize = 50;
+
+/// Undo and redo for a text signal.
+///
+/// A snapshot is only taken once the text has stopped changing for a
+/// moment, so undo steps back a word or so at a time, not a keystroke.
+#[derive(Clone, Copy)]
+struct UndoHistory {
+    value: ReadSignal<String>,
+    set_value: WriteSignal<String>,
+    /// The most recent snapshot, which `past` and `future` are relative to.
+    current: StoredValue<String>,
+    past: StoredValue<Vec<String>>,
+    future: StoredValue<Vec<String>>,
+}
+
+impl UndoHistory {
+    fn new(cx: Scope, value: ReadSignal<String>, set_value: WriteSignal<String>) -> Self {
+        let history = Self::with_manual_commits(cx, value, set_value);
+        let settled = debounce(cx, value, 500);
+        create_effect(cx, move |_| {
+            settled.track();
+            history.commit();
+        });
+        history
+    }
+
+    /// A history that only takes a snapshot when `commit` is called (or
+    /// before an undo or redo).
+    fn with_manual_commits(
+        cx: Scope,
+        value: ReadSignal<String>,
+        set_value: WriteSignal<String>,
+    ) -> Self {
+        Self {
+            value,
+            set_value,
+            current: store_value(cx, value.get_untracked()),
+            past: store_value(cx, Vec::new()),
+            future: store_value(cx, Vec::new()),
+        }
+    }
+
+    /// Takes a snapshot of the value, if it's changed since the last one.
+    /// A new edit means the undone versions can't be redone any more.
+    fn commit(&self) {
+        let value = self.value.get_untracked();
+        if self.current.with_value(|current| *current == value) {
+            return;
+        }
+        let previous = self.current.get_value();
+        self.past.update_value(|past| {
+            past.push(previous);
+            if past.len() > MAX_HISTORY {
+                past.remove(0);
+            }
+        });
+        self.current.set_value(value);
+        self.future.update_value(Vec::clear);
+    }
+
+    fn undo(&self) {
+        // An edit that hasn't been snapshotted yet is the first thing to undo.
+        self.commit();
+        let Some(previous) = self.past.try_update_value(Vec::pop).flatten() else {
+            return;
+        };
+        let current = self.current.get_value();
+        self.future.update_value(|future| future.push(current));
+        self.restore(previous);
+    }
+
+    fn redo(&self) {
+        self.commit();
+        let Some(next) = self.future.try_update_value(Vec::pop).flatten() else {
+            return;
+        };
+        let current = self.current.get_value();
+        self.past.update_value(|past| past.push(current));
+        self.restore(next);
+    }
+
+    /// Makes `snapshot` the current value. It's recorded as `current` first,
+    /// so that `commit` sees nothing new when the debounce catches up.
+    fn restore(&self, snapshot: String) {
+        self.current.set_value(snapshot.clone());
+        self.set_value.set(snapshot);
+    }
+}
+
+/// The longest name, in characters, that the form accepts.
+const MAX_NAME_LENGTH: usize = 20;
+
+/// Checks that a name isn't blank and isn't too long, returning a
+/// message describing the problem if it isn't valid.
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        Err("Name can't be empty.".to_string())
+    } else if name.chars().count() > MAX_NAME_LENGTH {
+        Err(format!(
+            "Name can't be longer than {MAX_NAME_LENGTH} characters."
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[component]
+pub fn AppThree(cx: Scope) -> impl IntoView {
+    const DEFAULT_NAME: &str = "Controlled";
+    const DEFAULT_NAME_TWO: &str = "Uncontrolled";
+
+    let (name, set_name) = create_signal(cx, DEFAULT_NAME.to_string());
+    let (name_two, set_name_two) = create_signal(cx, DEFAULT_NAME_TWO.to_string());
+    let (last_name, set_last_name) = create_signal(cx, String::new());
+
+    let input_element: NodeRef<Input> = create_node_ref(cx);
+    let toasts = use_context::<ToastContext>(cx);
+    let rating = create_rw_signal(cx, 0);
+    let phone = create_rw_signal(cx, String::new());
+    let bio = create_rw_signal(cx, "Tell us about yourself.".to_string());
+    let toppings = create_rw_signal(cx, Vec::new());
+    let size = create_rw_signal(cx, "medium".to_string());
+
+    let debounced_name = debounce(cx, name, 300);
+    let save_status = use_auto_save(cx, name);
+    let history = UndoHistory::new(cx, name, set_name);
+
+    let validation = move || name.with(|name| validate_name(name));
+    let is_valid = move || validation().is_ok();
+    // `len()` would count bytes, so "é" would count as 2.
+    let name_length = move || name.with(|name| name.chars().count());
+    let too_long = move || name_length() > MAX_NAME_LENGTH;
+
+    let on_input_handler = move |ev| {
+        set_name.set(event_target_value(&ev));
+    };
+    // Pasting a full name, like "Ada Lovelace", puts the first word in this
+    // input and the rest in the last name. Anything without a space pastes
+    // as usual.
+    let on_paste = move |ev: Event| {
+        // Leptos types this as a plain `Event`, since `ClipboardEvent` is
+        // one of `web-sys`'s unstable APIs.
+        let Some(text) = ev
+            .dyn_ref::<web_sys::ClipboardEvent>()
+            .and_then(|ev| ev.clipboard_data())
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return;
+        };
+        let Some((first, rest)) = text.trim().split_once(char::is_whitespace) else {
+            return;
+        };
+        // Otherwise the browser would paste the whole text as well.
+        ev.prevent_default();
+        set_name.set(first.to_string());
+        set_last_name.set(rest.trim().to_string());
+    };
+    // Saves `value` as Name Two, from whichever input it was entered in.
+    let submit = move |value: String| {
+        if is_valid() && validate_name(&value).is_ok() {
+            set_name_two.set(value);
+            if let Some(toasts) = toasts {
+                toasts.push_kind("Saved!", ToastKind::Success);
+            }
+        }
+    };
+    // Pressing Enter in an input inside a `<form>` makes the browser submit
+    // it, which fires `on:submit` on the form. The first input isn't inside
+    // the form, so it has to listen for Enter itself, and submits its own
+    // value rather than the form's. Both handlers call `prevent_default`,
+    // which for `submit` stops the page from reloading, and for Enter would
+    // stop a double submit if the input were ever moved into the form.
+    let on_keydown = move |ev: KeyboardEvent| {
+        if ev.key() == "Enter" {
+            ev.prevent_default();
+            submit(name.get_untracked());
+            return;
+        }
+        // `meta_key` is the ⌘ key, which is used instead of Ctrl on macOS.
+        if !(ev.ctrl_key() || ev.meta_key()) || !ev.key().eq_ignore_ascii_case("z") {
+            return;
+        }
+        // The browser's own undo doesn't know about our history, and would
+        // be overwritten by `prop:value` anyway.
+        ev.prevent_default();
+        if ev.shift_key() {
+            history.redo();
+        } else {
+            history.undo();
+        }
+    };
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        submit(input_element.get().expect("<input> to exist").value());
+    };
+    // Pretends to ask a server whether the name is taken.
+    let (checking, set_checking) = create_signal(cx, false);
+    let check_name = move |_| {
+        set_checking.set(true);
+        spawn_local(async move {
+            sleep(Duration::from_secs(1)).await;
+            set_checking.set(false);
+            if let Some(toasts) = toasts {
+                toasts.push(format!("\"{}\" is available!", name.get_untracked()));
+            }
+        });
+    };
+    let on_reset = move |_| {
+        set_name.set(DEFAULT_NAME.to_string());
+        set_name_two.set(DEFAULT_NAME_TWO.to_string());
+        set_last_name.set(String::new());
+    };
+
+    view! {cx,
+    <input
+    type="text"
+    on:input=on_input_handler
+    on:keydown=on_keydown
+    on:paste=on_paste
+    prop:value=move || name.get()
+    />
+    <input
+    type="text"
+    placeholder="Last name"
+    on:input=move |ev| set_last_name.set(event_target_value(&ev))
+    prop:value=move || last_name.get()
+    />
+    <small>"Ctrl+Z to undo, Ctrl+Shift+Z to redo. Paste a full name to fill in both."</small>
+    <div class:red=too_long>
+    {name_length} "/" {MAX_NAME_LENGTH}
+    </div>
+    <p>
+    "Name is:" {debounced_name} " " {last_name} " " <CopyButton text=name/> " "
+    <Button on_click=check_name loading=checking disabled=Signal::derive(cx, move || !is_valid())>
+    "Check availability"
+    </Button>
+    </p>
+    <p>
+    {move || match save_status.get() {
+        SaveStatus::Idle => "",
+        SaveStatus::Saving => "Saving…",
+        SaveStatus::Saved => "Saved ✓",
+    }}
+    </p>
+    <p class="red">{move || validation().err()}</p>
+
+    <form on:submit=on_submit>
+    // This input is uncontrolled: typing doesn't update `name_two`, and we
+    // only read its value through the `NodeRef` on submit. Binding
+    // `prop:value` to the signal (the `value` attribute only sets the
+    // initial value) means it still shows any later `set_name_two` call.
+    <input type="text"
+    prop:value=move || name_two.get()
+    node_ref=input_element
+    />
+    <input type="submit" value="Submit" disabled=move || !is_valid()/>
+    // `type="button"` stops this from submitting the form.
+    <Button on_click=on_reset>"Reset"</Button>
+    </form>
+    <p>"Name Two is:" {name_two}</p>
+
+    <h2>"Numbers"</h2>
+    <ParsedNumber/>
+
+    <h2>"Phone"</h2>
+    <MaskedInput pattern="(###) ###-####" value=phone/>
+    <p>"Phone is: " {phone}</p>
+
+    <h2>"Bio"</h2>
+    <AutoTextarea value=bio placeholder="A few lines about you"/>
+    <p>{move || bio.with(|bio| bio.lines().count())} " lines"</p>
+
+    <h2>"Toppings"</h2>
+    <CheckboxGroup
+    options=["Cheese", "Mushrooms", "Olives", "Peppers"].map(String::from).to_vec()
+    selected=toppings
+    />
+    <p>"Toppings are: " {move || toppings.get().join(", ")}</p>
+
+    <h2>"Size"</h2>
+    <RadioGroup
+    name="size"
+    options=vec![
+        ("small".to_string(), "Small".to_string()),
+        ("medium".to_string(), "Medium".to_string()),
+        ("large".to_string(), "Large".to_string()),
+    ]
+    value=size
+    />
+    <p>"Size is: " {size}</p>
+
+    <h2>"Avatar"</h2>
+    <FilePreview/>
+
+    <h2>"Rating"</h2>
+    <StarRating value=rating/>
+    <p>"Rating is: " {rating}</p>
+
+    <h2>"Search"</h2>
+    <p>"Fruits matching the name above (try \"error\" to see a failed search):"</p>
+    <SearchBox query=debounced_name/>
+
+    <h2>"Autocomplete"</h2>
+    <Autocomplete options=FRUITS.iter().map(|fruit| fruit.to_string()).collect()/>
+
+    <h2>"Sign Up"</h2>
+    <Wizard>
+    <WizardStep title="Account">
+    <label>"Email " <input type="email"/></label>
+    </WizardStep>
+    <WizardStep title="Profile">
+    <label>"Display name " <input type="text"/></label>
+    </WizardStep>
+    <WizardStep title="Done">
+    <p>"All set!"</p>
+    </WizardStep>
+    </Wizard>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs `f` with an `UndoHistory` of a signal that starts as `initial`,
+    /// and the setter for that signal.
+    fn with_history(initial: &str, f: impl FnOnce(UndoHistory, WriteSignal<String>) + 'static) {
+        let initial = initial.to_string();
+        let runtime = create_runtime();
+        create_scope(runtime, move |cx| {
+            let (value, set_value) = create_signal(cx, initial);
+            f(
+                UndoHistory::with_manual_commits(cx, value, set_value),
+                set_value,
+            );
+        })
+        .dispose();
+        runtime.dispose();
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_snapshots() {
+        with_history("a", |history, set_value| {
+            let value = move || history.value.get_untracked();
+            set_value.set("ab".to_string());
+            history.commit();
+            set_value.set("abc".to_string());
+            history.commit();
+
+            history.undo();
+            assert_eq!(value(), "ab");
+            history.undo();
+            assert_eq!(value(), "a");
+            // There's nothing older to go back to.
+            history.undo();
+            assert_eq!(value(), "a");
+
+            history.redo();
+            assert_eq!(value(), "ab");
+            history.redo();
+            assert_eq!(value(), "abc");
+            history.redo();
+            assert_eq!(value(), "abc");
+        });
+    }
+
+    #[test]
+    fn undo_first_undoes_an_edit_that_hasnt_been_committed() {
+        with_history("a", |history, set_value| {
+            set_value.set("ab".to_string());
+            history.undo();
+            assert_eq!(history.value.get_untracked(), "a");
+            history.redo();
+            assert_eq!(history.value.get_untracked(), "ab");
+        });
+    }
+
+    #[test]
+    fn a_new_edit_clears_redo() {
+        with_history("a", |history, set_value| {
+            set_value.set("ab".to_string());
+            history.commit();
+            history.undo();
+
+            set_value.set("ax".to_string());
+            history.commit();
+            history.redo();
+            assert_eq!(history.value.get_untracked(), "ax");
+        });
+    }
+
+    #[test]
+    fn history_keeps_at_most_max_history_snapshots() {
+        with_history("0", |history, set_value| {
+            for n in 1..=MAX_HISTORY + 10 {
+                set_value.set(n.to_string());
+                history.commit();
+            }
+            assert_eq!(history.past.with_value(Vec::len), MAX_HISTORY);
+
+            for _ in 0..MAX_HISTORY + 10 {
+                history.undo();
+            }
+            // The oldest snapshots were dropped to make room.
+            assert_eq!(history.value.get_untracked(), "10");
+        });
+    }
+
+    #[test]
+    fn validate_name_rejects_blank_names() {
+        assert!(validate_name("").is_err());
+        assert!(validate_name("   ").is_err());
+        assert!(validate_name("\t\n").is_err());
+    }
+
+    #[test]
+    fn validate_name_accepts_up_to_the_maximum_length() {
+        assert_eq!(validate_name("Ada"), Ok(()));
+        assert_eq!(validate_name(&"a".repeat(MAX_NAME_LENGTH)), Ok(()));
+        assert!(validate_name(&"a".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn validate_name_counts_characters_rather_than_bytes() {
+        assert_eq!(validate_name(&"é".repeat(MAX_NAME_LENGTH)), Ok(()));
+        assert!(validate_name(&"é".repeat(MAX_NAME_LENGTH + 1)).is_err());
+    }
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use crate::test_utils::{button, mount, type_into};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// The first `<input>` inside `root` that matches `selector`.
+    fn input(root: &web_sys::HtmlElement, selector: &str) -> web_sys::HtmlInputElement {
+        root.query_selector(selector)
+            .unwrap()
+            .unwrap()
+            .unchecked_into()
+    }
+
+    #[wasm_bindgen_test]
+    fn uncontrolled_input_shows_later_changes_to_name_two() {
+        let root = mount(|cx| view! { cx, <AppThree/> });
+        let uncontrolled = input(&root, "form input[type=text]");
+        assert_eq!(uncontrolled.value(), "Uncontrolled");
+
+        // Typing doesn't touch the signal until the form is submitted.
+        uncontrolled.set_value("Grace");
+        assert!(!root.text_content().unwrap().contains("Name Two is:Grace"));
+        input(&root, "input[type=submit]").click();
+        assert!(root.text_content().unwrap().contains("Name Two is:Grace"));
+
+        button(&root, "Reset").click();
+        assert_eq!(uncontrolled.value(), "Uncontrolled");
+    }
+
+    #[wasm_bindgen_test]
+    fn name_length_counts_characters_rather_than_bytes() {
+        let root = mount(|cx| view! { cx, <AppThree/> });
+        type_into(&input(&root, "input"), "ééé");
+        let text = root.text_content().unwrap();
+        assert!(text.contains(&format!("3/{MAX_NAME_LENGTH}")), "{text}");
+    }
+
+    #[wasm_bindgen_test]
+    fn enter_in_the_first_input_submits_its_own_value() {
+        let root = mount(|cx| view! { cx, <AppThree/> });
+        type_into(&input(&root, "input"), "Grace");
+
+        let mut init = web_sys::KeyboardEventInit::new();
+        init.key("Enter").bubbles(true);
+        let enter =
+            web_sys::KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+        input(&root, "input").dispatch_event(&enter).unwrap();
+        assert!(root.text_content().unwrap().contains("Name Two is:Grace"));
+        // The form's own input now shows it too, since it follows Name Two.
+        assert_eq!(input(&root, "form input[type=text]").value(), "Grace");
+    }
+}
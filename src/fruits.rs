@@ -0,0 +1,67 @@
+//! A page per fruit, whose details load slowly, to show how `<Transition>`
+//! keeps the old page on screen while the new one loads.
+//!
+//! `<Suspense>` and `<Transition>` both wait for the resources read inside
+//! them. The difference is what they show in the meantime:
+//!
+//! - `<Suspense>` shows its `fallback` every time a resource loads, so going
+//!   from one fruit to the next would blank out the page and show
+//!   "Loading…" until the new details arrive.
+//! - `<Transition>` only shows its `fallback` the first time. After that, it
+//!   keeps showing what it had until the new resources are ready, and
+//!   reports that it's waiting through `set_pending`.
+
+use crate::search::{sleep, FRUITS};
+use leptos::*;
+use leptos_router::*;
+use std::time::Duration;
+
+/// Pretends to fetch the details of the fruit called `name` from a slow
+/// server.
+async fn load_fruit(name: String) -> Option<String> {
+    sleep(Duration::from_secs(1)).await;
+    let index = FRUITS
+        .iter()
+        .position(|fruit| fruit.eq_ignore_ascii_case(&name))?;
+    Some(format!(
+        "{} is number {} of the {} fruits we know about.",
+        FRUITS[index],
+        index + 1,
+        FRUITS.len()
+    ))
+}
+
+/// The page at `/fruits/:name`.
+#[component]
+pub fn FruitPage(cx: Scope) -> impl IntoView {
+    // Going from one fruit to another keeps this component, and just
+    // changes the params, which reloads the resource.
+    let params = use_params_map(cx);
+    let name = move || params.with(|params| params.get("name").cloned().unwrap_or_default());
+    let details = create_local_resource(cx, name, load_fruit);
+    let (pending, set_pending) = create_signal(cx, false);
+
+    view! { cx,
+        <h1>"Fruits"</h1>
+        <nav>
+            {FRUITS
+                .iter()
+                .take(6)
+                .map(|fruit| {
+                    let href = format!("/fruits/{}", fruit.to_lowercase());
+                    view! { cx, <A href=href>{*fruit}</A>" " }
+                })
+                .collect_view(cx)}
+        </nav>
+        <p class:hidden=move || !pending.get()>"Navigating…"</p>
+        <Transition fallback=move || view! { cx, <p>"Loading…"</p> } set_pending=set_pending.into()>
+            {move || {
+                details.read(cx).map(|details| match details {
+                    Some(details) => view! { cx, <p>{details}</p> }.into_view(cx),
+                    None => view! { cx, <p class="red">"There's no fruit called " {name()} "."</p> }
+                        .into_view(cx),
+                })
+            }}
+        </Transition>
+    }
+}
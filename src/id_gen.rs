@@ -0,0 +1,16 @@
+use std::cell::Cell;
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns an ID that's never been returned before.
+///
+/// The count is shared by the whole app and never goes back, so an ID stays
+/// unique even after whatever it was given to is removed, cleared, or
+/// replaced by an import. It starts again from 0 when the page reloads, so
+/// it's only for telling apart things that exist at the same time, like
+/// rows of a list or elements on the page.
+pub fn next_id() -> usize {
+    NEXT_ID.with(|next| next.replace(next.get() + 1))
+}
@@ -0,0 +1,1230 @@
+use crate::{
+    announcer::Announcer, confirm_button::ConfirmButton, id_gen::next_id, pagination::Pagination,
+    search::sleep,
+};
+use leptos::{
+    ev::{DragEvent, KeyboardEvent},
+    html::Div,
+    *,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    hash::Hash,
+    time::Duration,
+};
+
+/// A `<ul>` with an `<li>` for each of `items`, rendered with `<For>`.
+///
+/// Like `<For>`, each row is keyed by `key`, so when `items` changes only
+/// the rows whose keys were added or removed are created or destroyed, and
+/// rows that move keep their DOM nodes (and any state inside them).
+#[component]
+pub fn List<T, K, KF, V, VF>(
+    cx: Scope,
+    #[prop(into)]
+    /// The items to show, one per row.
+    items: Signal<Vec<T>>,
+    /// Returns a key for an item that's unique within the list.
+    key: KF,
+    /// Renders the contents of the row for an item.
+    view: VF,
+    #[prop(optional)]
+    /// Shown instead of the list while `items` is empty, e.g.,
+    /// `empty=Box::new(|cx| view! { cx, <p>"Nothing here"</p> }.into_view(cx))`.
+    empty: Option<Box<dyn Fn(Scope) -> View>>,
+) -> impl IntoView
+where
+    T: Clone + 'static,
+    K: Eq + Hash + 'static,
+    KF: Fn(&T) -> K + 'static,
+    V: IntoView,
+    VF: Fn(Scope, T) -> V + 'static,
+{
+    // A memo, so the placeholder is only created or removed when the list
+    // goes from empty to not, rather than on every change.
+    let is_empty = create_memo(cx, move |_| items.with(Vec::is_empty));
+
+    // The placeholder gets a scope of its own, which is disposed when it's
+    // removed, so anything `empty` creates doesn't pile up in `cx` each
+    // time the list empties.
+    let placeholder_scope = store_value(cx, None::<ScopeDisposer>);
+    let placeholder = move || {
+        let is_empty = is_empty.get();
+        placeholder_scope.update_value(|disposer| {
+            if let Some(disposer) = disposer.take() {
+                disposer.dispose();
+            }
+        });
+        let empty = empty.as_ref().filter(|_| is_empty)?;
+        let (view, disposer) = cx.run_child_scope(empty);
+        placeholder_scope.set_value(Some(disposer));
+        Some(view)
+    };
+
+    // The `<ul>` is hidden rather than removed while the list is empty, so
+    // the `<For>` is only ever created once. Creating a new one each time
+    // items came back would throw away the old one's rows and start over.
+    view! { cx,
+        {placeholder}
+        <ul class:hidden=move || is_empty.get()>
+            <For
+                each=move || items.get()
+                key=key
+                view=move |cx, item| view! { cx, <li>{view(cx, item)}</li> }
+            />
+        </ul>
+    }
+}
+
+/// A list of counters, without the ability
+/// to add or remove any.
+#[component]
+pub fn StaticList(
+    cx: Scope,
+    /// How many counters to include in this list.
+    length: usize,
+    #[prop(default = 1)]
+    /// The initial value of the first counter.
+    start: usize,
+) -> impl IntoView {
+    let counters = (start..start + length)
+        .map(|idx| create_signal(cx, idx))
+        .collect::<Vec<_>>();
+
+    // A memo only reruns when one of the signals it reads changes, and then
+    // reads each counter once, no matter how many times `total` is used.
+    let count_signals = counters.iter().map(|(count, _)| *count).collect::<Vec<_>>();
+    let total = create_memo(cx, move |_| {
+        count_signals.iter().map(|count| count.get()).sum::<usize>()
+    });
+
+    // The setters are kept, alongside the rows that also use them, so that
+    // one button can update every counter.
+    let setters = store_value(
+        cx,
+        counters
+            .iter()
+            .map(|(_, set_count)| *set_count)
+            .collect::<Vec<_>>(),
+    );
+    // Without the batch, `total` would recompute once for every counter.
+    let increment_all = move |_| {
+        cx.batch(|| {
+            setters.with_value(|setters| {
+                for set_count in setters {
+                    set_count.update(|n| *n += 1);
+                }
+            })
+        })
+    };
+
+    let counter_buttons = counters
+        .into_iter()
+        .map(|(count, set_count)| {
+            view! { cx,
+                <li>
+                    <button
+                        on:click=move |_| set_count.update(|n| *n += 1)
+                    >
+                        {count}
+                    </button>
+                </li>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! { cx,
+        <button on:click=increment_all>"Increment All"</button>
+        <ul>{counter_buttons}</ul>
+        <p>"Total: " {total}</p>
+    }
+}
+
+/// A counter in `DynamicList`, in the form it's exported and imported as
+/// JSON.
+///
+/// The ID is saved along with the value, but imported counters are given
+/// new ones by [`reassign_ids`], so they can't clash with any that are
+/// already in use.
+#[derive(Serialize, Deserialize)]
+struct SavedCounter {
+    id: usize,
+    value: usize,
+}
+
+/// Gives each of the `saved` counters a new ID from `new_id`, keeping them
+/// in order, and returns the new IDs along with the values.
+///
+/// Fails if two of the counters were saved with the same ID, which means
+/// the JSON has been edited and it's not clear which row was which.
+fn reassign_ids(
+    saved: Vec<SavedCounter>,
+    mut new_id: impl FnMut() -> usize,
+) -> Result<Vec<(usize, usize)>, String> {
+    let mut saved_ids = HashSet::new();
+    saved
+        .into_iter()
+        .map(|counter| {
+            if !saved_ids.insert(counter.id) {
+                return Err(format!("more than one counter has the id {}", counter.id));
+            }
+            Ok((new_id(), counter.value))
+        })
+        .collect()
+}
+
+/// How many removals `DynamicList` remembers for "Undo".
+const MAX_UNDO: usize = 10;
+
+/// One of `DynamicList`'s counters: its ID, and the signal holding its value.
+type CounterRow = (usize, (ReadSignal<usize>, WriteSignal<usize>));
+
+/// The total of the values of `counters`.
+///
+/// The set of counters changes over time, so the memo first reads the list
+/// itself, and then each counter in it. It reruns when a counter is added
+/// or removed, or when any of their values change.
+fn counters_sum(cx: Scope, counters: ReadSignal<Vec<CounterRow>>) -> Memo<usize> {
+    create_memo(cx, move |_| {
+        counters.with(|counters| {
+            counters
+                .iter()
+                .map(|(_, (count, _))| count.get())
+                .sum::<usize>()
+        })
+    })
+}
+
+/// Removes the counter with the ID `id`, and remembers it in `removed`
+/// along with the index it was removed from, so that "Undo" can put it
+/// back where it was. Returns whether there was a counter with that ID.
+///
+/// `cx.batch` holds off running effects until both signals have been
+/// updated, so anything that reads both of them (like "Undo", which is
+/// disabled while `removed` is empty or the list is full) only updates
+/// once, not once per signal.
+fn remove_and_remember(
+    cx: Scope,
+    set_counters: WriteSignal<Vec<CounterRow>>,
+    set_removed: WriteSignal<Vec<(usize, CounterRow)>>,
+    id: usize,
+) -> bool {
+    cx.batch(|| {
+        let counter = set_counters
+            .try_update(|counters| {
+                let index = counters
+                    .iter()
+                    .position(|(counter_id, _)| counter_id == &id)?;
+                Some((index, counters.remove(index)))
+            })
+            .flatten();
+        let removed_any = counter.is_some();
+        if let Some(counter) = counter {
+            set_removed.update(|removed| {
+                removed.push(counter);
+                if removed.len() > MAX_UNDO {
+                    removed.remove(0);
+                }
+            });
+        }
+        removed_any
+    })
+}
+
+/// A list of counters that allows you to add or
+/// remove counters.
+#[component]
+pub fn DynamicList(
+    cx: Scope,
+    #[prop(optional)]
+    /// The number of counters to begin with, counting up from 1.
+    initial_length: usize,
+    #[prop(optional)]
+    /// The values of the counters to begin with. If set, this is used
+    /// instead of `initial_length`.
+    initial_values: Option<Vec<usize>>,
+    #[prop(optional)]
+    /// The most counters the list can hold. Unlimited if not set.
+    max_counters: Option<usize>,
+) -> impl IntoView {
+    let initial_counters = initial_values
+        .unwrap_or_else(|| (1..=initial_length).collect())
+        .into_iter()
+        .map(|value| (next_id(), create_signal(cx, value)))
+        .collect::<Vec<_>>();
+
+    let (counters, set_counters) = create_signal(cx, initial_counters);
+
+    // Both the "Add Counter" button and every row need to create counters.
+    // `next_id` never hands out the same ID twice, so they don't have to
+    // keep track of which IDs have been used. The IDs are shared with the
+    // rest of the app, so they'd make odd starting values: a new counter
+    // starts at one more than the number of counters instead.
+    let new_counter = move || {
+        let value = counters.with_untracked(Vec::len) + 1;
+        (next_id(), create_signal(cx, value))
+    };
+
+    let sum = counters_sum(cx, counters);
+
+    let at_limit = move || max_counters.is_some_and(|max| counters.with(Vec::len) >= max);
+
+    // Screen readers don't notice rows being added or removed by
+    // themselves, so each change is announced.
+    let announcer = use_context::<Announcer>(cx);
+    let announce = move |text: String| {
+        if let Some(announcer) = announcer {
+            announcer.announce(text);
+        }
+    };
+
+    let add_counter = move |_| {
+        let counter = new_counter();
+        set_counters.update(move |counters| counters.push(counter));
+        announce(format!(
+            "Counter added, {} in total",
+            counters.with_untracked(Vec::len)
+        ));
+    };
+
+    // Swaps the counter with the given ID with the one `offset` rows away,
+    // if there is one.
+    let move_counter = move |id: usize, offset: isize| {
+        set_counters.update(|counters| {
+            let Some(index) = counters
+                .iter()
+                .position(|(counter_id, _)| counter_id == &id)
+            else {
+                return;
+            };
+            if let Some(other) = index.checked_add_signed(offset) {
+                if other < counters.len() {
+                    counters.swap(index, other);
+                }
+            }
+        });
+    };
+    // Removed counters are kept along with the index they were removed
+    // from, so that "Undo" can put them back where they were. We keep the
+    // signals themselves, rather than their values, so nothing is lost.
+    let (removed, set_removed) = create_signal(cx, Vec::new());
+    let remove_counter = move |id: usize| {
+        if remove_and_remember(cx, set_counters, set_removed, id) {
+            announce(format!(
+                "Counter removed, {} remaining",
+                counters.with_untracked(Vec::len)
+            ));
+        }
+    };
+
+    // Only one row is in the tab order at a time (a "roving tabindex"), so
+    // Tab moves past the whole list in one go, and j/k or the arrow keys
+    // move between rows. This is the ID of that row, if it's been chosen.
+    let (focused, set_focused) = create_signal(cx, None::<usize>);
+    // The row that's in the tab order. If the focused row has been removed,
+    // or none has been focused yet, it's the first one.
+    let tab_stop = create_memo(cx, move |_| {
+        let focused = focused.get();
+        counters.with(|counters| {
+            focused
+                .filter(|id| counters.iter().any(|(counter_id, _)| counter_id == id))
+                .or_else(|| counters.first().map(|(id, _)| *id))
+        })
+    });
+    // Each row's element, so focus can be moved to it by ID.
+    let row_refs = store_value(cx, HashMap::<usize, NodeRef<Div>>::new());
+    let focus_row = move |id: usize| {
+        set_focused.set(Some(id));
+        if let Some(row) = row_refs.with_value(|rows| rows.get(&id).and_then(|row| row.get())) {
+            _ = row.focus();
+        }
+    };
+    // The ID of the row `offset` rows away from the one with the ID `id`.
+    let neighbor = move |id: usize, offset: isize| {
+        counters.with_untracked(|counters| {
+            let index = counters
+                .iter()
+                .position(|(counter_id, _)| *counter_id == id)?;
+            counters
+                .get(index.checked_add_signed(offset)?)
+                .map(|(id, _)| *id)
+        })
+    };
+    // Removing the focused row would leave focus nowhere, so it moves to
+    // the row below, or the one above if it was the last.
+    let remove_and_refocus = move |id: usize| {
+        let next = (focused.get_untracked() == Some(id))
+            .then(|| neighbor(id, 1).or_else(|| neighbor(id, -1)))
+            .flatten();
+        remove_counter(id);
+        if let Some(next) = next {
+            focus_row(next);
+        }
+    };
+    let on_row_keydown = move |ev: KeyboardEvent, id: usize, set_count: WriteSignal<usize>| {
+        // Keys pressed on the buttons inside the row keep working as usual.
+        if ev.target() != ev.current_target() {
+            return;
+        }
+        match ev.key().as_str() {
+            "j" | "ArrowDown" => {
+                if let Some(next) = neighbor(id, 1) {
+                    focus_row(next);
+                }
+            }
+            "k" | "ArrowUp" => {
+                if let Some(previous) = neighbor(id, -1) {
+                    focus_row(previous);
+                }
+            }
+            "Enter" => set_count.update(|n| *n += 1),
+            "Delete" | "Backspace" => remove_and_refocus(id),
+            _ => return,
+        }
+        // Otherwise the arrow keys would also scroll the page.
+        ev.prevent_default();
+    };
+
+    let undo_remove = move |_| {
+        cx.batch(|| {
+            let Some((index, counter)) = set_removed.try_update(|removed| removed.pop()).flatten()
+            else {
+                return;
+            };
+            set_counters.update(|counters| {
+                // Other rows may have been removed in the meantime.
+                let index = index.min(counters.len());
+                counters.insert(index, counter);
+            });
+        })
+    };
+
+    // The ID of the row that's being dragged, if any.
+    let (dragged, set_dragged) = create_signal(cx, None::<usize>);
+    // Moves the dragged counter into the place of the one with the ID
+    // `target`, or to the end of the list if `target` is `None`.
+    let drop_counter = move |target: Option<usize>| {
+        let Some(dragged_id) = dragged.get_untracked() else {
+            return;
+        };
+        cx.batch(|| {
+            set_dragged.set(None);
+            // Dropping a row onto itself leaves it where it is.
+            if target == Some(dragged_id) {
+                return;
+            }
+            set_counters.update(|counters| {
+                let position = |id| {
+                    counters
+                        .iter()
+                        .position(|(counter_id, _)| *counter_id == id)
+                };
+                let Some(from) = position(dragged_id) else {
+                    return;
+                };
+                // Find where the target is before removing anything, so a
+                // row dragged downward ends up below the target, and one
+                // dragged upward ends up above it.
+                let to = target.and_then(position);
+                let counter = counters.remove(from);
+                let to = to.unwrap_or(counters.len()).min(counters.len());
+                counters.insert(to, counter);
+            });
+        })
+    };
+    // The browser only lets you drop onto elements that cancel `dragover`.
+    let allow_drop = |ev: DragEvent| ev.prevent_default();
+
+    // The JSON in the export/import box, and what was wrong with it if it
+    // couldn't be imported.
+    let (json, set_json) = create_signal(cx, String::new());
+    let (import_error, set_import_error) = create_signal(cx, None::<String>);
+    let export = move |_| {
+        let saved = counters.with(|counters| {
+            counters
+                .iter()
+                .map(|(id, (count, _))| SavedCounter {
+                    id: *id,
+                    value: count.get_untracked(),
+                })
+                .collect::<Vec<_>>()
+        });
+        // Serializing a `Vec` of plain structs can't fail.
+        set_json.set(serde_json::to_string_pretty(&saved).unwrap_or_default());
+        set_import_error.set(None);
+    };
+    let import = move |_| {
+        let saved = json
+            .with_untracked(|json| serde_json::from_str::<Vec<SavedCounter>>(json))
+            .map_err(|err| err.to_string())
+            .and_then(|saved| reassign_ids(saved, next_id));
+        let saved = match saved {
+            Ok(saved) => saved,
+            Err(err) => {
+                set_import_error.set(Some(format!("Couldn't import: {err}")));
+                return;
+            }
+        };
+        if max_counters.is_some_and(|max| saved.len() > max) {
+            set_import_error.set(Some(
+                "Couldn't import: that's more counters than the limit.".to_string(),
+            ));
+            return;
+        }
+        cx.batch(|| {
+            set_counters.set(
+                saved
+                    .into_iter()
+                    .map(|(id, value)| (id, create_signal(cx, value)))
+                    .collect(),
+            );
+            // Undoing a removal from before the import would mix the old
+            // list back into the new one.
+            set_removed.update(Vec::clear);
+            set_import_error.set(None);
+        });
+    };
+
+    let is_first =
+        move |id: usize| counters.with(|counters| counters.first().map(|c| c.0) == Some(id));
+    let is_last =
+        move |id: usize| counters.with(|counters| counters.last().map(|c| c.0) == Some(id));
+
+    view! { cx,
+        <div>
+            <button on:click=add_counter disabled=at_limit>
+                "Add Counter"
+            </button>
+            <Show when=at_limit fallback=|_| ()>
+                <small>"(limit reached)"</small>
+            </Show>
+            // New counters still get IDs that were never used before, so
+            // they can't be confused with a removed one.
+            <ConfirmButton
+                label="Remove All"
+                confirm_label="Really remove all?"
+                on_confirm=move |_| {
+                    set_counters.update(|counters| counters.clear());
+                    announce("All counters removed".to_string());
+                }
+            />
+            <button
+                on:click=undo_remove
+                disabled=move || removed.with(Vec::is_empty) || at_limit()
+            >
+                "Undo"
+            </button>
+            // Dropping below the last row, rather than onto a row, moves the
+            // dragged counter to the end.
+            <div
+                on:dragover=allow_drop
+                on:drop=move |ev: DragEvent| {
+                    ev.prevent_default();
+                    drop_counter(None);
+                }
+            >
+                <List
+                    items=counters
+                    empty=Box::new(|cx| view! { cx, <p>"No counters — add one!"</p> }.into_view(cx))
+                    key=|counter| counter.0
+                    view=move |cx, (id, (count, set_count))| {
+                        let row: NodeRef<Div> = create_node_ref(cx);
+                        row_refs.update_value(|rows| {
+                            rows.insert(id, row);
+                        });
+                        on_cleanup(cx, move || {
+                            // Undo brings a row back with the same ID, so only
+                            // forget it if it's really gone.
+                            if !counters.with_untracked(|counters| {
+                                counters.iter().any(|(counter_id, _)| *counter_id == id)
+                            }) {
+                                row_refs.update_value(|rows| {
+                                    rows.remove(&id);
+                                });
+                            }
+                        });
+                        view! { cx,
+                            <div
+                                node_ref=row
+                                tabindex=move || if tab_stop.get() == Some(id) { 0 } else { -1 }
+                                on:focusin=move |_| set_focused.set(Some(id))
+                                on:keydown=move |ev| on_row_keydown(ev, id, set_count)
+                                draggable="true"
+                                on:dragstart=move |ev: DragEvent| {
+                                    // Firefox won't start a drag without some data.
+                                    if let Some(data) = ev.data_transfer() {
+                                        _ = data.set_data("text/plain", &id.to_string());
+                                    }
+                                    set_dragged.set(Some(id));
+                                }
+                                on:dragend=move |_| set_dragged.set(None)
+                                on:dragover=allow_drop
+                                on:drop=move |ev: DragEvent| {
+                                    ev.prevent_default();
+                                    // Otherwise the `<div>` around the list would also
+                                    // handle it, and move the counter to the end.
+                                    ev.stop_propagation();
+                                    drop_counter(Some(id));
+                                }
+                            >
+                                <button
+                                    on:click=move |_| set_count.update(|n| *n += 1)
+                                >
+                                    {count}
+                                </button>
+                                <button on:click=move |_| remove_and_refocus(id)>
+                                    "Remove"
+                                </button>
+                                <button
+                                    on:click=move |_| {
+                                        let counter = new_counter();
+                                        set_counters.update(|counters| {
+                                            // Look up the index when clicked, since rows
+                                            // above this one may have been added or removed.
+                                            if let Some(index) = counters
+                                                .iter()
+                                                .position(|(counter_id, _)| counter_id == &id)
+                                            {
+                                                counters.insert(index, counter);
+                                            }
+                                        });
+                                        announce(format!(
+                                            "Counter added, {} in total",
+                                            counters.with_untracked(Vec::len)
+                                        ));
+                                    }
+                                    disabled=at_limit
+                                >
+                                    "Insert Above"
+                                </button>
+                                // `<List>` keys rows by ID, so moving a counter moves its
+                                // existing DOM nodes along with its current value.
+                                <button
+                                    on:click=move |_| move_counter(id, -1)
+                                    disabled=move || is_first(id)
+                                >
+                                    "↑"
+                                </button>
+                                <button
+                                    on:click=move |_| move_counter(id, 1)
+                                    disabled=move || is_last(id)
+                                >
+                                    "↓"
+                                </button>
+                            </div>
+                        }
+                    }
+                />
+            </div>
+            <p>
+                <small>
+                    "Focus a row, then use j/k or ↓/↑ to move between rows, Enter to "
+                    "increment it, and Delete or Backspace to remove it."
+                </small>
+            </p>
+            <p>"Sum: " {sum}</p>
+            <textarea
+                rows="4"
+                placeholder="Export the list, or paste JSON here to import it"
+                prop:value=move || json.get()
+                on:input=move |ev| set_json.set(event_target_value(&ev))
+            />
+            <br/>
+            <button on:click=export>"Export"</button>
+            <button on:click=import>"Import"</button>
+            <p class="red">{import_error}</p>
+        </div>
+    }
+}
+
+/// A long list of counters, shown `page_size` at a time.
+#[component]
+pub fn PaginatedList(
+    cx: Scope,
+    /// How many counters to include in this list.
+    length: usize,
+    /// How many counters to show on each page.
+    page_size: usize,
+) -> impl IntoView {
+    // `Pagination` counts a `page_size` of 0 as 1, so the rows do too, rather
+    // than every page being empty.
+    let page_size = page_size.max(1);
+    let counters = (1..=length)
+        .map(|idx| create_signal(cx, idx))
+        .collect::<Vec<_>>();
+    let page = create_rw_signal(cx, 0);
+
+    // Only the counters on the current page are rendered. The others keep
+    // their values, since their signals aren't tied to the rows.
+    let rows = move || {
+        counters
+            .iter()
+            .skip(page.get() * page_size)
+            .take(page_size)
+            .map(|&(count, set_count)| {
+                view! { cx,
+                    <li>
+                        <button on:click=move |_| set_count.update(|n| *n += 1)>
+                            {count}
+                        </button>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! { cx,
+        <ul>{rows}</ul>
+        <Pagination total=length page_size=page_size current=page/>
+    }
+}
+
+/// A very long list of counters, of which only the rows that are scrolled
+/// into view (plus a few either side) are actually rendered.
+#[component]
+pub fn VirtualList(
+    cx: Scope,
+    /// How many counters to include in this list.
+    length: usize,
+    #[prop(default = 30)]
+    /// The height of each row in pixels. Every row must be this tall.
+    row_height: usize,
+    #[prop(default = 300)]
+    /// The height of the scrolling area in pixels.
+    height: usize,
+    #[prop(default = 5)]
+    /// How many extra rows to render above and below the visible ones, so
+    /// scrolling quickly doesn't show blank space.
+    overscan: usize,
+) -> impl IntoView {
+    let row_height = row_height.max(1);
+    let counters = (1..=length)
+        .map(|idx| create_signal(cx, idx))
+        .collect::<Vec<_>>();
+
+    let container: NodeRef<Div> = create_node_ref(cx);
+    let (scroll_top, set_scroll_top) = create_signal(cx, 0);
+    let (viewport_height, set_viewport_height) = create_signal(cx, height);
+
+    let on_scroll = move |_| {
+        if let Some(container) = container.get() {
+            set_scroll_top.set(container.scroll_top().max(0) as usize);
+            set_viewport_height.set(container.client_height().max(0) as usize);
+        }
+    };
+
+    // Memos rather than plain closures, so a scroll that stays within the
+    // same row doesn't rebuild the rows or move the list.
+    let first_row = create_memo(cx, move |_| {
+        (scroll_top.get() / row_height).saturating_sub(overscan)
+    });
+    let visible_range = create_memo(cx, move |_| {
+        let first = first_row.get();
+        let row_count = viewport_height.get().div_ceil(row_height) + 2 * overscan;
+        first.min(length)..(first + row_count).min(length)
+    });
+    let visible_rows = move || {
+        counters[visible_range.get()]
+            .iter()
+            .map(|&(count, set_count)| {
+                view! { cx,
+                    <li style=format!("height: {row_height}px")>
+                        <button on:click=move |_| set_count.update(|n| *n += 1)>
+                            {count}
+                        </button>
+                    </li>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! { cx,
+        <div
+            node_ref=container
+            on:scroll=on_scroll
+            style=format!("height: {height}px; overflow-y: auto")
+        >
+            // The spacer is as tall as every row would be, so the scrollbar
+            // behaves as if the whole list were there.
+            <div style=format!("position: relative; height: {}px", length * row_height)>
+                <ul
+                    class="virtual-list"
+                    style=move || format!("position: absolute; top: {}px", first_row.get() * row_height)
+                >
+                    {visible_rows}
+                </ul>
+            </div>
+        </div>
+    }
+}
+
+/// Why [`load_counters`] failed.
+#[derive(Clone, Debug)]
+struct LoadError;
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The server didn't respond.")
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Pretends to fetch the values of some saved counters from a server, or
+/// to fail to, if `fail` is set.
+async fn load_counters(fail: bool) -> Result<Vec<usize>, LoadError> {
+    sleep(Duration::from_secs(1)).await;
+    if fail {
+        Err(LoadError)
+    } else {
+        Ok(vec![3, 1, 4, 1, 5])
+    }
+}
+
+/// A [`DynamicList`] whose counters are loaded from a server first.
+///
+/// The loaded counters only bring their values: each is given a new ID
+/// from `next_id`, as imported ones are, so they can't clash with any ID
+/// handed out before or after.
+#[component]
+pub fn LoadedDynamicList(cx: Scope) -> impl IntoView {
+    let (fail, set_fail) = create_signal(cx, false);
+    // Changing `fail` loads the counters again.
+    let counters = create_local_resource(cx, move || fail.get(), load_counters);
+
+    // Shaped roughly like the list, so the page doesn't jump once it loads.
+    let skeleton = move || {
+        view! { cx,
+            <div aria-busy="true" aria-label="Loading counters">
+                {(0..5).map(|_| view! { cx, <div class="skeleton"/> }).collect_view(cx)}
+            </div>
+        }
+    };
+
+    view! { cx,
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=move || fail.get()
+                on:change=move |ev| set_fail.set(event_target_checked(&ev))
+            />
+            " Make loading fail"
+        </label>
+        <Suspense fallback=skeleton>
+            <ErrorBoundary
+                fallback=move |cx, errors| view! { cx,
+                    <p class="red">
+                        "Couldn't load the counters: "
+                        {move || {
+                            errors
+                                .get()
+                                .into_iter()
+                                .map(|(_, error)| error.to_string())
+                                .collect::<Vec<_>>()
+                                .join(" ")
+                        }}
+                    </p>
+                    <button on:click=move |_| counters.refetch()>"Retry"</button>
+                }
+            >
+                // Once loaded, the list owns its counters, so it keeps
+                // working like any other `DynamicList`.
+                {move || {
+                    counters.read(cx).map(|result| {
+                        result.map(|values| view! { cx, <DynamicList initial_values=values/> })
+                    })
+                }}
+            </ErrorBoundary>
+        </Suspense>
+    }
+}
+
+#[component]
+pub fn AppTwo(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <h1>"Iteration"</h1>
+        <h2>"Static List"</h2>
+        <p>"Use this pattern if the list itself is static."</p>
+        <StaticList length=5/>
+        <h2>"Dynamic List"</h2>
+        <p>"Use this pattern if the rows in your list will change."</p>
+        <DynamicList initial_length=5/>
+        <h2>"Loaded List"</h2>
+        <p>"Use this pattern if the rows start out on a server."</p>
+        <LoadedDynamicList/>
+        <h2>"Paginated List"</h2>
+        <p>"Use this pattern if the list is too long to show at once."</p>
+        <PaginatedList length=95 page_size=10/>
+        <h2>"Virtual List"</h2>
+        <p>"Use this pattern if the list is too long to render every row."</p>
+        <VirtualList length=10_000/>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+    use std::{cell::Cell, rc::Rc};
+
+    #[test]
+    fn paginated_list_shows_one_row_per_page_for_a_page_size_of_zero() {
+        let html = render_html(|cx| view! { cx, <PaginatedList length=3 page_size=0/> });
+        assert_eq!(html.matches("<li>").count(), 1, "{html}");
+    }
+
+    #[test]
+    fn dynamic_list_shows_the_sum_of_its_counters() {
+        let html = render_html(|cx| view! { cx, <DynamicList initial_length=3/> });
+        assert!(html.contains("<p>Sum: 6</p>"), "{html}");
+        let empty = render_html(|cx| view! { cx, <DynamicList initial_length=0/> });
+        assert!(empty.contains("<p>Sum: 0</p>"), "{empty}");
+    }
+
+    #[test]
+    fn dynamic_list_stops_adding_at_max_counters() {
+        let full = render_html(|cx| view! { cx, <DynamicList initial_length=2 max_counters=2/> });
+        assert!(
+            full.contains("<button disabled>Add Counter</button>"),
+            "{full}"
+        );
+        assert!(full.contains("(limit reached)"), "{full}");
+
+        let not_full =
+            render_html(|cx| view! { cx, <DynamicList initial_length=1 max_counters=2/> });
+        assert!(
+            not_full.contains("<button>Add Counter</button>"),
+            "{not_full}"
+        );
+        assert!(!not_full.contains("(limit reached)"), "{not_full}");
+    }
+
+    #[test]
+    fn dynamic_list_disables_moving_past_either_end() {
+        let html = render_html(|cx| view! { cx, <DynamicList initial_length=2/> });
+        let first_row = "<button>1</button><button>Remove</button><button>Insert Above</button><button disabled>↑</button><button>↓</button>";
+        let last_row = "<button>2</button><button>Remove</button><button>Insert Above</button><button>↑</button><button disabled>↓</button>";
+        assert!(html.contains(first_row), "{html}");
+        assert!(html.contains(last_row), "{html}");
+    }
+
+    #[test]
+    fn static_list_counts_up_from_start() {
+        let html = render_html(|cx| view! { cx, <StaticList length=3 start=100/> });
+        assert!(
+            html.contains("<li><button>100</button></li><li><button>101</button></li><li><button>102</button></li></ul>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn static_list_shows_the_total_of_its_counters() {
+        let html = render_html(|cx| view! { cx, <StaticList length=3 start=100/> });
+        assert!(html.contains("<p>Total: 303</p>"), "{html}");
+    }
+
+    #[test]
+    fn static_list_starts_from_1_by_default() {
+        let html = render_html(|cx| view! { cx, <StaticList length=2/> });
+        assert!(
+            html.contains("<ul><li><button>1</button></li><li><button>2</button></li></ul>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn removing_a_counter_updates_the_sum_and_undo_list_in_one_go() {
+        let runtime = create_runtime();
+        let runs = Rc::new(Cell::new(0));
+        create_scope(runtime, {
+            let runs = Rc::clone(&runs);
+            move |cx| {
+                let rows = [1, 2, 3].map(|value| (value, create_signal(cx, value)));
+                let (counters, set_counters) = create_signal(cx, rows.to_vec());
+                let (removed, set_removed) = create_signal(cx, Vec::new());
+                let sum = counters_sum(cx, counters);
+                // Like "Undo", this reads both the list and `removed`.
+                create_effect(cx, {
+                    let runs = Rc::clone(&runs);
+                    move |_| {
+                        sum.track();
+                        removed.track();
+                        runs.set(runs.get() + 1);
+                    }
+                });
+                assert_eq!(runs.get(), 1);
+
+                assert!(remove_and_remember(cx, set_counters, set_removed, 2));
+                assert_eq!(runs.get(), 2);
+                assert_eq!(sum.get_untracked(), 4);
+                assert_eq!(removed.with_untracked(|removed| removed[0].0), 1);
+
+                // There's no longer a counter with the ID 2.
+                assert!(!remove_and_remember(cx, set_counters, set_removed, 2));
+                assert_eq!(runs.get(), 2);
+            }
+        })
+        .dispose();
+        runtime.dispose();
+    }
+
+    #[test]
+    fn reassign_ids_gives_each_counter_a_new_id_in_order() {
+        let saved = vec![
+            SavedCounter { id: 7, value: 3 },
+            SavedCounter { id: 2, value: 1 },
+            SavedCounter { id: 40, value: 4 },
+        ];
+        let mut next = 100..;
+        let counters = reassign_ids(saved, || next.next().unwrap());
+        assert_eq!(counters, Ok(vec![(100, 3), (101, 1), (102, 4)]));
+    }
+
+    #[test]
+    fn reassign_ids_rejects_duplicate_ids() {
+        let saved = vec![
+            SavedCounter { id: 1, value: 3 },
+            SavedCounter { id: 1, value: 5 },
+        ];
+        assert!(reassign_ids(saved, next_id).is_err());
+    }
+
+    #[test]
+    fn saved_counters_round_trip_through_json() {
+        let json = r#"[{"id":4,"value":10},{"id":9,"value":0}]"#;
+        let saved = serde_json::from_str::<Vec<SavedCounter>>(json).unwrap();
+        assert_eq!(serde_json::to_string(&saved).unwrap(), json);
+    }
+
+    #[test]
+    fn virtual_list_only_renders_the_visible_rows() {
+        let html = leptos::ssr::render_to_string(|cx| {
+            view! { cx, <VirtualList length=10_000/> }
+        });
+        // 300px / 30px visible rows, plus 5 rows of overscan on each side.
+        assert_eq!(html.matches("<li").count(), 20, "{html}");
+    }
+
+    #[test]
+    fn virtual_list_renders_every_row_of_a_short_list() {
+        let html = leptos::ssr::render_to_string(|cx| {
+            view! { cx, <VirtualList length=3/> }
+        });
+        assert_eq!(html.matches("<li").count(), 3, "{html}");
+    }
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use crate::test_utils::{button, buttons, mount};
+    use std::{cell::Cell, rc::Rc};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// The text of the first element inside `root` that matches `selector`.
+    fn text(root: &web_sys::HtmlElement, selector: &str) -> String {
+        root.query_selector(selector)
+            .unwrap()
+            .and_then(|element| element.text_content())
+            .unwrap_or_default()
+    }
+
+    /// The values of the counters in the list inside `root`, from the top.
+    fn values(root: &web_sys::HtmlElement) -> Vec<String> {
+        let counters = root.query_selector_all("li button:first-child").unwrap();
+        (0..counters.length())
+            .filter_map(|index| counters.item(index)?.text_content())
+            .collect()
+    }
+
+    /// How many rows are in the list inside `root`.
+    fn row_count(root: &web_sys::HtmlElement) -> u32 {
+        root.query_selector_all("li").unwrap().length()
+    }
+
+    #[wasm_bindgen_test]
+    fn list_disposes_of_the_placeholder_once_there_are_items() {
+        let cleaned_up = Rc::new(Cell::new(false));
+        let items = Rc::new(Cell::new(None));
+        let root = mount({
+            let (cleaned_up, items) = (Rc::clone(&cleaned_up), Rc::clone(&items));
+            move |cx| {
+                let list = create_rw_signal(cx, Vec::<usize>::new());
+                items.set(Some(list));
+                let empty = move |cx| {
+                    let cleaned_up = Rc::clone(&cleaned_up);
+                    on_cleanup(cx, move || cleaned_up.set(true));
+                    view! { cx, <p>"Nothing here"</p> }.into_view(cx)
+                };
+                view! { cx,
+                    <List
+                        items=list
+                        key=|n| *n
+                        view=|_, n| n
+                        empty=Box::new(empty)
+                    />
+                }
+            }
+        });
+        assert_eq!(text(&root, "p"), "Nothing here");
+        assert!(!cleaned_up.get());
+
+        items.get().unwrap().set(vec![1]);
+        assert!(root.query_selector("p").unwrap().is_none());
+        assert!(cleaned_up.get());
+    }
+
+    #[wasm_bindgen_test]
+    fn static_list_total_follows_each_counter() {
+        let root = mount(|cx| view! { cx, <StaticList length=3/> });
+        assert_eq!(text(&root, "p"), "Total: 6");
+
+        button(&root, "2").click();
+        assert_eq!(text(&root, "p"), "Total: 7");
+    }
+
+    #[wasm_bindgen_test]
+    fn increment_all_increments_every_counter() {
+        let root = mount(|cx| view! { cx, <StaticList length=3/> });
+        assert_eq!(values(&root), ["1", "2", "3"]);
+
+        button(&root, "Increment All").click();
+        assert_eq!(values(&root), ["2", "3", "4"]);
+        assert_eq!(text(&root, "p"), "Total: 9");
+    }
+
+    #[wasm_bindgen_test]
+    fn remove_all_clears_the_list_once_confirmed() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=3/> });
+        let remove_all = button(&root, "Remove All");
+
+        remove_all.click();
+        assert_eq!(row_count(&root), 3);
+        remove_all.click();
+        assert_eq!(row_count(&root), 0);
+        assert!(root.text_content().unwrap().contains("No counters"));
+
+        // Adding still works afterward.
+        button(&root, "Add Counter").click();
+        assert_eq!(row_count(&root), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn arrow_buttons_swap_rows_with_their_neighbors() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=3/> });
+
+        buttons(&root, "↓")[0].click();
+        assert_eq!(values(&root), ["2", "1", "3"]);
+        buttons(&root, "↑")[2].click();
+        assert_eq!(values(&root), ["2", "3", "1"]);
+
+        // The counters keep their values as they move.
+        button(&root, "3").click();
+        buttons(&root, "↑")[1].click();
+        assert_eq!(values(&root), ["4", "2", "1"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn removing_a_counter_below_the_limit_allows_adding_again() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=1 max_counters=2/> });
+        let add = button(&root, "Add Counter");
+
+        add.click();
+        assert_eq!(row_count(&root), 2);
+        assert!(add.has_attribute("disabled"));
+
+        button(&root, "Remove").click();
+        assert!(!add.has_attribute("disabled"));
+    }
+
+    #[wasm_bindgen_test]
+    fn sum_follows_counters_changing_and_being_removed() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=3/> });
+        let sum = || {
+            root.text_content()
+                .unwrap()
+                .split("Sum: ")
+                .nth(1)
+                .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+                .map(str::to_string)
+        };
+        assert_eq!(sum().as_deref(), Some("6"));
+
+        button(&root, "1").click();
+        assert_eq!(sum().as_deref(), Some("7"));
+
+        buttons(&root, "Remove")[0].click();
+        assert_eq!(sum().as_deref(), Some("5"));
+    }
+
+    #[wasm_bindgen_test]
+    fn row_ids_are_never_reused() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=2/> });
+        let textarea = root
+            .query_selector("textarea")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlTextAreaElement>();
+        let export = || {
+            button(&root, "Export").click();
+            serde_json::from_str::<Vec<SavedCounter>>(&textarea.value()).unwrap()
+        };
+
+        // Every ID that's been on the page so far. Once a row is gone, its
+        // ID shouldn't come back.
+        let mut seen = HashSet::new();
+        let mut current = HashSet::new();
+        let mut check_new_ids = |step: &str| {
+            let ids = export()
+                .into_iter()
+                .map(|counter| counter.id)
+                .collect::<HashSet<_>>();
+            for id in ids.difference(&current) {
+                assert!(seen.insert(*id), "{step} reused the id {id}");
+            }
+            current = ids;
+        };
+        check_new_ids("starting");
+
+        buttons(&root, "Remove")[0].click();
+        button(&root, "Add Counter").click();
+        check_new_ids("adding after removing");
+
+        let saved = textarea.value();
+        let remove_all = button(&root, "Remove All");
+        remove_all.click();
+        remove_all.click();
+        button(&root, "Add Counter").click();
+        check_new_ids("adding after removing all");
+
+        // Importing what was exported earlier gives those rows new IDs
+        // rather than their old ones.
+        textarea.set_value(&saved);
+        let mut init = web_sys::EventInit::new();
+        init.bubbles(true);
+        let input = web_sys::Event::new_with_event_init_dict("input", &init).unwrap();
+        textarea.dispatch_event(&input).unwrap();
+        button(&root, "Import").click();
+        assert_eq!(row_count(&root), 2);
+        check_new_ids("importing");
+    }
+
+    #[wasm_bindgen_test]
+    fn new_counters_start_one_past_the_number_of_counters() {
+        let root = mount(|cx| view! { cx, <DynamicList initial_length=2/> });
+
+        button(&root, "Add Counter").click();
+        assert_eq!(values(&root), ["1", "2", "3"]);
+
+        buttons(&root, "Remove")[0].click();
+        button(&root, "Add Counter").click();
+        assert_eq!(values(&root), ["2", "3", "3"]);
+    }
+}
@@ -1,242 +1,206 @@
-use leptos::{ev::SubmitEvent, html::Input, *};
-
-/// Shows progress toward a goal.
+mod accordion;
+mod announcer;
+mod auto_textarea;
+mod autocomplete;
+#[cfg(feature = "bench")]
+mod bench;
+mod button;
+mod callback;
+mod checkbox_group;
+mod clipboard;
+mod collapse;
+mod color_input;
+mod confirm_button;
+mod control_flow;
+mod document;
+mod error_handling;
+mod events;
+mod file_preview;
+mod forms;
+mod fruits;
+mod id_gen;
+mod lists;
+mod masked_input;
+mod modal;
+mod not_found;
+mod number_input;
+mod pagination;
+mod progress;
+mod radio_group;
+mod reactive_utils;
+mod search;
+#[cfg(feature = "ssr")]
+mod server;
+mod shared_counter;
+mod slider;
+mod split_pane;
+mod star_rating;
+mod storage;
+mod tabs;
+mod temperature;
+#[cfg(test)]
+mod test_utils;
+mod theme;
+mod timers;
+mod toast;
+mod todos;
+mod toggle_section;
+mod tooltip;
+mod wizard;
+
+pub use accordion::{Accordion, AccordionSection};
+pub use announcer::{provide_announcer, Announcer, LiveRegion};
+pub use auto_textarea::AutoTextarea;
+pub use autocomplete::Autocomplete;
+pub use button::Button;
+pub use callback::Callback;
+pub use checkbox_group::CheckboxGroup;
+pub use clipboard::CopyButton;
+pub use collapse::Collapse;
+pub use color_input::ColorInput;
+pub use confirm_button::ConfirmButton;
+pub use control_flow::AppFour;
+pub use document::set_document_title;
+pub use error_handling::ParsedNumber;
+pub use events::{use_event_listener, use_window_size};
+pub use file_preview::FilePreview;
+pub use forms::AppThree;
+pub use fruits::FruitPage;
+pub use id_gen::next_id;
+pub use lists::{
+    AppTwo, DynamicList, List, LoadedDynamicList, PaginatedList, StaticList, VirtualList,
+};
+pub use masked_input::MaskedInput;
+pub use modal::Modal;
+pub use not_found::{NotFound, ResponseStatus};
+pub use number_input::NumberInput;
+pub use pagination::Pagination;
+pub use progress::{AppOne, Counter, ProgressBar, SegmentedProgressBar};
+pub use radio_group::RadioGroup;
+pub use reactive_utils::{
+    debounce, logged_memo, map2, throttle, use_debounced_effect, use_interval, zip,
+    IntervalControls,
+};
+pub use search::SearchBox;
+pub use shared_counter::{CounterButton, CounterContext, CounterDisplay, CounterProvider};
+pub use slider::Slider;
+pub use split_pane::{LeftPane, RightPane, SplitPane};
+pub use star_rating::StarRating;
+pub use storage::use_local_storage;
+pub use tabs::{Tab, Tabs};
+pub use temperature::{AppSeven, TempConverter};
+pub use theme::{provide_theme, ThemeContext, ThemeToggle};
+pub use timers::{AppFive, Clock, Countdown, Stopwatch};
+pub use toast::{provide_toasts, Toast, ToastContext, ToastHost, ToastKind};
+pub use todos::{AppSix, Todo, TodoList};
+pub use toggle_section::ToggleSection;
+pub use tooltip::Tooltip;
+pub use wizard::{Wizard, WizardStep};
+
+use leptos::{ev::MouseEvent, html::Main, *};
+use leptos_router::*;
+
+/// The window width, in pixels, below which the nav is collapsed.
+const NARROW_WIDTH: f64 = 600.0;
+
+/// Links to each of the demos, and renders whichever one matches the
+/// current URL.
 #[component]
-fn ProgressBar(
-    // All components take a reactive `Scope` as the first argument
-    cx: Scope,
-    // Marks this as an optional prop. It will default to the default
-    // value of its type, i.e., 0.
-    #[prop(default = 100)]
-    /// The maximum value of the progress bar.
-    max: u16,
-    // Will run `.into()` on the value passed into the prop.
-    #[prop(into)]
-    // `Signal<T>` is a wrapper for several reactive types.
-    // It can be helpful in component APIs like this, where we
-    // might want to take any kind of reactive value
-    /// How much progress should be displayed.
-    progress: Signal<i32>,
-) -> impl IntoView {
-    view! { cx,
-        <progress
-            max={max}
-            value={move || progress.get()}
-        />
-        <br/>
-    }
-}
+fn App(cx: Scope) -> impl IntoView {
+    let ThemeContext { dark, .. } = provide_theme(cx);
+    provide_toasts(cx);
+    provide_announcer(cx);
+
+    // On narrow screens the nav takes up too much room, so it's hidden
+    // behind a "Menu" button instead. The server doesn't know the width
+    // (it reports 0), so it renders the whole nav.
+    let window_size = use_window_size(cx);
+    let narrow = move || {
+        let width = window_size.get().0;
+        width > 0.0 && width < NARROW_WIDTH
+    };
+    let (menu_open, set_menu_open) = create_signal(cx, false);
 
-#[component]
-fn AppOne(cx: Scope) -> impl IntoView {
-    let (count, set_count) = create_signal(cx, 0);
-    let double_count = move || count.get() * 2;
+    // Moves focus to the demo itself, so keyboard users don't have to tab
+    // through the whole nav on every page. Handling this ourselves, rather
+    // than linking to `#main`, also keeps the router out of it.
+    let main_ref: NodeRef<Main> = create_node_ref(cx);
+    let skip_to_content = move |ev: MouseEvent| {
+        ev.prevent_default();
+        if let Some(main) = main_ref.get() {
+            _ = main.focus();
+        }
+    };
 
     view! { cx,
-        <button
-            on:click=move |_| {
-                set_count.update(|n| *n += 1);
-            }
-            class:red = move || count.get() % 2 == 1
-        >
-            "Click me"
-        </button>
-        <br/>
-        <ProgressBar max=50 progress=count/>
-        <ProgressBar progress=count/>
-        <ProgressBar max=50 progress=Signal::derive(cx, double_count)/>
-    }
-}
-
-/// A list of counters, without the ability
-/// to add or remove any.
-#[component]
-fn StaticList(
-    cx: Scope,
-    /// How many counters to include in this list.
-    length: usize,
-) -> impl IntoView {
-    let counters = (1..=length).map(|idx| create_signal(cx, idx));
-
-    let counter_buttons = counters
-        .map(|(count, set_count)| {
-            view! { cx,
-                <li>
+        <Router>
+            <div class="root" class:dark=move || dark.get()>
+                <a class="skip-link" href="#main" on:click=skip_to_content>
+                    "Skip to content"
+                </a>
+                // `<A>` sets `aria-current="page"` on the link for the current
+                // route, which screen readers announce and the CSS highlights.
+                <Show when=narrow fallback=|_| ()>
                     <button
-                        on:click=move |_| set_count.update(|n| *n += 1)
+                        aria-expanded=move || menu_open.get().to_string()
+                        on:click=move |_| set_menu_open.update(|open| *open = !*open)
                     >
-                        {count}
+                        "Menu"
                     </button>
-                </li>
-            }
-        })
-        .collect::<Vec<_>>();
-
-    view! { cx,
-        <ul>{counter_buttons}</ul>
+                </Show>
+                <nav class:hidden=move || narrow() && !menu_open.get()>
+                    <A href="/progress">"Progress"</A>" "
+                    <A href="/lists">"Lists"</A>" "
+                    <A href="/forms">"Forms"</A>" "
+                    <A href="/control-flow">"Control Flow"</A>" "
+                    <A href="/timers">"Timers"</A>" "
+                    <A href="/todos">"Todos"</A>" "
+                    <A href="/temperature">"Temperature"</A>" "
+                    <A href="/fruits/apple">"Fruits"</A>" "
+                    <ThemeToggle/>
+                </nav>
+                <hr/>
+                // `tabindex="-1"` lets the skip link focus this, without adding
+                // it to the tab order.
+                <main id="main" tabindex="-1" node_ref=main_ref>
+                    <Routes>
+                        <Route path="" view=|cx| view! { cx, <AppOne/> }/>
+                        <Route path="/progress" view=|cx| view! { cx, <AppOne/> }/>
+                        <Route path="/lists" view=|cx| view! { cx, <AppTwo/> }/>
+                        <Route path="/forms" view=|cx| view! { cx, <AppThree/> }/>
+                        <Route path="/control-flow" view=|cx| view! { cx, <AppFour/> }/>
+                        <Route path="/timers" view=|cx| view! { cx, <AppFive/> }/>
+                        <Route path="/todos" view=|cx| view! { cx, <AppSix/> }/>
+                        <Route path="/temperature" view=|cx| view! { cx, <AppSeven/> }/>
+                        <Route path="/fruits/:name" view=|cx| view! { cx, <FruitPage/> }/>
+                        // `*any` matches every path that none of the routes above did.
+                        <Route path="/*any" view=|cx| view! { cx, <NotFound/> }/>
+                    </Routes>
+                </main>
+                <ToastHost/>
+                <LiveRegion/>
+            </div>
+        </Router>
     }
 }
 
-/// A list of counters that allows you to add or
-/// remove counters.
-#[component]
-fn DynamicList(
-    cx: Scope,
-    /// The number of counters to begin with.
-    initial_length: usize,
-) -> impl IntoView {
-    let mut next_counter_id = initial_length;
-
-    let initial_counters = (0..initial_length)
-        .map(|id| (id, create_signal(cx, id + 1)))
-        .collect::<Vec<_>>();
-
-    let (counters, set_counters) = create_signal(cx, initial_counters);
-
-    let add_counter = move |_| {
-        let sig = create_signal(cx, next_counter_id + 1);
-        set_counters.update(move |counters| counters.push((next_counter_id, sig)));
-        // increment the ID so it's always unique
-        next_counter_id += 1;
-    };
-
-    view! { cx,
-        <div>
-            <button on:click=add_counter>
-                "Add Counter"
-            </button>
-            <ul>
-                <For
-                    each=move || counters.get()
-                    key=|counter| counter.0
-                    view=move |cx, (id, (count, set_count))| {
-                        view! { cx,
-                            <li>
-                                <button
-                                    on:click=move |_| set_count.update(|n| *n += 1)
-                                >
-                                    {count}
-                                </button>
-                                <button
-                                    on:click=move |_| {
-                                        set_counters.update(|counters| {
-                                            counters.retain(|(counter_id, _)| counter_id != &id)
-                                        });
-                                    }
-                                >
-                                    "Remove"
-                                </button>
-                            </li>
-                        }
-                    }
-                />
-            </ul>
-        </div>
-    }
-}
-
-#[component]
-fn AppTwo(cx: Scope) -> impl IntoView {
-    view! { cx,
-        <h1>"Iteration"</h1>
-        <h2>"Static List"</h2>
-        <p>"Use this pattern if the list itself is static."</p>
-        <StaticList length=5/>
-        <h2>"Dynamic List"</h2>
-        <p>"Use this pattern if the rows in your list will change."</p>
-        <DynamicList initial_length=5/>
-    }
-}
-
-#[component]
-fn AppThree(cx: Scope) -> impl IntoView {
-    let (name, set_name) = create_signal(cx, "Controlled".to_string());
-    let (name_two, set_name_two) = create_signal(cx, "Uncontrolled".to_string());
-
-    let input_element: NodeRef<Input> = create_node_ref(cx);
-
-    let on_input_handler = move |ev| {
-        set_name.set(event_target_value(&ev));
-    };
-    let on_submit = move |ev: SubmitEvent| {
-        ev.prevent_default();
-
-        let value = input_element.get().expect("<input> to exist").value();
-
-        set_name_two.set(value);
-    };
-
-    view! {cx,
-    <input
-    type="text"
-    on:input=on_input_handler
-    prop:value=name.get()
-    />
-    <p>"Name is:" {name}</p>
-
-    <form on:submit=on_submit>
-    <input type="text"
-    value=name_two.get()
-    node_ref=input_element
-    />
-    <input type="submit" value="Submit"/>
-    </form>
-    <p>"Name Two is:" {name_two}</p>
-    }
+// Leptos has no separate function for hydrating: with the `hydrate` feature,
+// building `App` here looks up the elements the server rendered (by the
+// hydration IDs it gave them) and attaches signals and event listeners to
+// them, and `mount_to_body` then moves them back into `<body>`. Without it,
+// every element is created from scratch.
+#[cfg(not(any(feature = "ssr", feature = "bench")))]
+fn main() {
+    leptos::mount_to_body(|cx| view! { cx, <App/> })
 }
 
-#[component]
-fn App(cx: Scope) -> impl IntoView {
-    let (value, set_value) = create_signal(cx, 0);
-
-    let is_odd = move || value.get() & 1 == 1;
-    let odd_text = move || if is_odd() { Some("How odd!") } else { None };
-
-    view! { cx,
-        <h1>"Control Flow"</h1>
-
-        <button on:click=move |_| set_value.update(|n| *n += 1)>
-            "+1"
-        </button>
-        <p>"Value is: " {value}</p>
-        <hr/>
-        <h2><code>"Option<T>"</code></h2>
-        <p>{odd_text}</p>
-        <p>{move || odd_text().map(|text| text.len())}</p>
-
-        <h2>"Conditional Logic"</h2>
-
-        <p>
-            {move || if is_odd() {
-                "Odd"
-            } else {
-                "Even"
-            }}
-        </p>
-
-        <p class:hidden=is_odd>"Appears if even."</p>
-
-        <Show when=is_odd
-            fallback=|cx| view! { cx, <p>"Even steven"</p> }
-        >
-            <p>"Oddment"</p>
-        </Show>
-
-        {move || is_odd().then(|| view! { cx, <p>"Oddity!"</p> })}
-
-        <h2>"Converting between Types"</h2>
-        {move || match is_odd() {
-            true if value.get() == 1 => {
-                view! { cx, <pre>"One"</pre> }.into_any()
-            },
-            false if value.get() == 2 => {
-                view! { cx, <p>"Two"</p> }.into_any()
-            }
-            _ => view! { cx, <textarea>{value.get()}</textarea> }.into_any()
-        }}
-    }
+#[cfg(all(feature = "bench", not(feature = "ssr")))]
+fn main() {
+    use bench::Bench;
+    leptos::mount_to_body(|cx| view! { cx, <Bench/> })
 }
 
+#[cfg(feature = "ssr")]
 fn main() {
-    leptos::mount_to_body(|cx| view! { cx, <App/> })
+    server::serve()
 }
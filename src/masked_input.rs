@@ -0,0 +1,234 @@
+use leptos::{ev::Event, *};
+use wasm_bindgen::JsCast;
+
+/// The character in a mask pattern that stands for a digit. Every other
+/// character is copied into the formatted text as-is.
+const DIGIT_SLOT: char = '#';
+
+/// Fills the digit slots of `pattern` with `digits`, in order.
+///
+/// The mask characters before a slot are only added once there's a digit
+/// to put in it, so `"(###) ###-####"` and `"1234"` give `"(123) 4"`, not
+/// `"(123) 4__-____"`. Digits beyond the last slot are dropped.
+fn apply_mask(pattern: &str, digits: &str) -> String {
+    let mut formatted = String::new();
+    let mut digits = digits.chars();
+    // Mask characters waiting for the next digit to arrive.
+    let mut pending = String::new();
+    for mask in pattern.chars() {
+        if mask == DIGIT_SLOT {
+            let Some(digit) = digits.next() else {
+                break;
+            };
+            formatted.push_str(&pending);
+            pending.clear();
+            formatted.push(digit);
+        } else {
+            pending.push(mask);
+        }
+    }
+    formatted
+}
+
+/// Returns the byte positions of the digits in `text` that fill the digit
+/// slots of `pattern`.
+///
+/// Digits that are part of the mask itself, like the `1` in
+/// `"+1 (###) ###-####"`, aren't counted, as long as they're where the mask
+/// puts them. `text` doesn't have to be formatted: it may be halfway through
+/// an edit, with characters missing or inserted.
+fn digit_positions(pattern: &str, text: &str) -> Vec<usize> {
+    let mut mask = pattern.chars().peekable();
+    let mut positions = Vec::new();
+    for (index, c) in text.char_indices() {
+        if mask.peek().is_some_and(|&m| m != DIGIT_SLOT && m == c) {
+            // The next mask character, where we expected it.
+            mask.next();
+        } else if c.is_ascii_digit() {
+            positions.push(index);
+            // Skip any mask characters that should have come first, and
+            // the slot this digit fills.
+            _ = mask.find(|&m| m == DIGIT_SLOT);
+        }
+    }
+    positions
+}
+
+/// Returns the digits in `text` that fill the digit slots of `pattern`.
+fn digits(pattern: &str, text: &str) -> String {
+    digit_positions(pattern, text)
+        .into_iter()
+        .map(|index| &text[index..=index])
+        .collect()
+}
+
+/// Returns the position just after the `count`th digit of `text` that fills
+/// a slot of `pattern`.
+fn caret_after_digits(pattern: &str, text: &str, count: usize) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    digit_positions(pattern, text)
+        .get(count - 1)
+        .map_or(text.len(), |index| index + 1)
+}
+
+/// Formats `raw`, the text of the input after an edit, and works out where
+/// the caret should go. `previous` is the formatted text before the edit,
+/// and `caret` is where the caret was in `raw`.
+fn reformat(
+    pattern: &str,
+    previous: &str,
+    raw: &str,
+    caret: usize,
+    deleted_backward: bool,
+) -> (String, usize) {
+    let slots = pattern.chars().filter(|c| *c == DIGIT_SLOT).count();
+    let mut digits = digits(pattern, raw);
+    let mut digits_before_caret = digit_positions(pattern, raw)
+        .into_iter()
+        .take_while(|index| *index < caret)
+        .count();
+
+    // Backspacing over a mask character doesn't remove any digits, so
+    // formatting would just put the same character straight back.
+    // Instead, delete the digit before it.
+    let previous_digits = digit_positions(pattern, previous).len();
+    if deleted_backward && digits.len() == previous_digits && digits_before_caret > 0 {
+        digits.remove(digits_before_caret - 1);
+        digits_before_caret -= 1;
+    }
+
+    digits.truncate(slots);
+    let formatted = apply_mask(pattern, &digits);
+    let caret = caret_after_digits(pattern, &formatted, digits_before_caret.min(slots));
+    (formatted, caret)
+}
+
+/// A text input that formats the digits typed into it to match `pattern`,
+/// e.g., `"(###) ###-####"` for a phone number.
+///
+/// Anything that isn't a digit is ignored, and the caret stays after the
+/// same digit it was after before formatting, wherever the mask characters
+/// end up.
+#[component]
+pub fn MaskedInput(
+    cx: Scope,
+    /// The mask, with a `#` for each digit.
+    pattern: &'static str,
+    /// The formatted text, including the mask characters.
+    value: RwSignal<String>,
+) -> impl IntoView {
+    let on_input = move |ev: Event| {
+        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+        let raw = input.value();
+        // `selection_start` counts UTF-16 code units, but the only text we
+        // care about before the caret is digits and ASCII mask characters.
+        let caret = input
+            .selection_start()
+            .ok()
+            .flatten()
+            .map_or(raw.len(), |caret| caret as usize);
+        let deleted_backward = ev
+            .dyn_ref::<web_sys::InputEvent>()
+            .is_some_and(|ev| ev.input_type() == "deleteContentBackward");
+
+        let (formatted, caret) = value
+            .with_untracked(|previous| reformat(pattern, previous, &raw, caret, deleted_backward));
+        // Updating the signal rewrites the input's value, which can move the
+        // caret to the end, so it has to be put back afterward.
+        value.set(formatted);
+        _ = input.set_selection_range(caret as u32, caret as u32);
+    };
+
+    view! { cx,
+        <input
+            type="text"
+            inputmode="numeric"
+            placeholder=pattern
+            on:input=on_input
+            prop:value=move || value.get()
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHONE: &str = "(###) ###-####";
+    const US_PHONE: &str = "+1 (###) ###-####";
+
+    #[test]
+    fn apply_mask_only_adds_mask_characters_before_filled_slots() {
+        assert_eq!(apply_mask(PHONE, ""), "");
+        assert_eq!(apply_mask(PHONE, "1234"), "(123) 4");
+        assert_eq!(apply_mask(PHONE, "1234567890"), "(123) 456-7890");
+        assert_eq!(apply_mask(PHONE, "123456789012"), "(123) 456-7890");
+        assert_eq!(apply_mask(US_PHONE, "555"), "+1 (555");
+    }
+
+    #[test]
+    fn digits_skips_digits_that_belong_to_the_mask() {
+        assert_eq!(digits(US_PHONE, "+1 (555) 12"), "55512");
+        assert_eq!(digits(US_PHONE, "+1 (555) 123-4567"), "5551234567");
+        // Typing into an empty input doesn't mistake the first digit for
+        // the mask's `1`.
+        assert_eq!(digits(US_PHONE, "1"), "1");
+        assert_eq!(digits(PHONE, "(123) 4x"), "1234");
+    }
+
+    #[test]
+    fn formatting_is_stable() {
+        for pattern in [PHONE, US_PHONE] {
+            let formatted = apply_mask(pattern, "1112223333");
+            assert_eq!(digits(pattern, &formatted), "1112223333");
+            assert_eq!(
+                reformat(pattern, &formatted, &formatted, 0, false).0,
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn caret_goes_after_the_same_digit() {
+        assert_eq!(caret_after_digits(US_PHONE, "+1 (555) 12", 0), 0);
+        assert_eq!(caret_after_digits(US_PHONE, "+1 (555) 12", 3), 7);
+        assert_eq!(caret_after_digits(US_PHONE, "+1 (555) 12", 4), 10);
+        assert_eq!(caret_after_digits(US_PHONE, "+1 (555) 12", 9), 11);
+    }
+
+    #[test]
+    fn typing_moves_the_caret_past_added_mask_characters() {
+        assert_eq!(
+            reformat(US_PHONE, "+1 (555", "+1 (5551", 8, false),
+            ("+1 (555) 1".to_string(), 10)
+        );
+        // Inserting in the middle keeps the caret after the new digit.
+        assert_eq!(
+            reformat(PHONE, "(123) 4", "(1923) 4", 3, false),
+            ("(192) 34".to_string(), 3)
+        );
+    }
+
+    #[test]
+    fn backspacing_over_a_mask_character_deletes_the_digit_before_it() {
+        // The caret was after the space, and it was the space that went.
+        assert_eq!(
+            reformat(PHONE, "(123) 4", "(123)4", 5, true),
+            ("(124".to_string(), 3)
+        );
+        assert_eq!(
+            reformat(US_PHONE, "+1 (555) 1", "+1 (555)1", 8, true),
+            ("+1 (551".to_string(), 6)
+        );
+    }
+
+    #[test]
+    fn backspacing_a_digit_just_removes_it() {
+        assert_eq!(
+            reformat(US_PHONE, "+1 (555) 1", "+1 (555) ", 9, true),
+            ("+1 (555".to_string(), 7)
+        );
+    }
+}
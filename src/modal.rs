@@ -0,0 +1,121 @@
+use crate::{callback::Callback, events::use_event_listener};
+use leptos::{ev::KeyboardEvent, html::Div, *};
+use wasm_bindgen::JsCast;
+
+/// Matches the elements inside a dialog that can receive focus.
+const FOCUSABLE: &str = "button, [href], input, select, textarea, [tabindex]:not([tabindex='-1'])";
+
+/// A dialog shown over the rest of the page while `show` is `true`.
+///
+/// Clicking the backdrop or pressing Escape calls `on_close`; it's up to
+/// the parent to actually set `show` to `false`. While open, focus is moved
+/// into the dialog and kept there, and it's given back to whatever had it
+/// before once the dialog closes.
+#[component]
+pub fn Modal(
+    cx: Scope,
+    /// Whether the dialog is open.
+    show: ReadSignal<bool>,
+    /// Called when the user asks to close the dialog.
+    #[prop(into)]
+    on_close: Callback<()>,
+    // `ChildrenFn` rather than `Children`, since the content is created
+    // again each time the dialog opens.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let dialog_ref: NodeRef<Div> = create_node_ref(cx);
+    let previous_focus = store_value(cx, None::<web_sys::HtmlElement>);
+
+    create_effect(cx, move |_| {
+        if show.get() {
+            let active = document()
+                .active_element()
+                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+            previous_focus.set_value(active);
+        } else if let Some(el) = previous_focus.get_value() {
+            _ = el.focus();
+            previous_focus.set_value(None);
+        }
+    });
+    // The dialog's element only exists once `<Show>` has rendered it, so we
+    // wait for the `NodeRef` to be filled in before focusing it.
+    create_effect(cx, move |_| {
+        if show.get() {
+            if let Some(dialog) = dialog_ref.get() {
+                _ = dialog.focus();
+            }
+        }
+    });
+
+    // Escape is handled on the window, so it works even if focus has
+    // somehow ended up outside the dialog. There's no window on the server.
+    if !cfg!(feature = "ssr") {
+        let on_close = on_close.clone();
+        use_event_listener(cx, window().into(), "keydown", move |ev| {
+            let is_escape = ev
+                .dyn_ref::<web_sys::KeyboardEvent>()
+                .is_some_and(|ev| ev.key() == "Escape");
+            if is_escape && show.get_untracked() {
+                on_close.call(());
+            }
+        });
+    }
+
+    let on_backdrop_click = move |_| on_close.call(());
+    // Keep Tab and Shift+Tab inside the dialog while it's open.
+    let on_keydown = move |ev: KeyboardEvent| {
+        if ev.key() != "Tab" {
+            return;
+        }
+        let Some(dialog) = dialog_ref.get_untracked() else {
+            return;
+        };
+        let Ok(focusable) = dialog.query_selector_all(FOCUSABLE) else {
+            return;
+        };
+        let len = focusable.length();
+        if len == 0 {
+            ev.prevent_default();
+            return;
+        }
+        let first = focusable.item(0);
+        let last = focusable.item(len - 1);
+        let active = document().active_element().map(web_sys::Node::from);
+        // The dialog itself has focus right after it opens.
+        let at_start = active == first || active == Some(web_sys::Node::from((*dialog).clone()));
+        // Wrap around at either end, instead of letting focus leave
+        // the dialog.
+        let wrap_to = if ev.shift_key() && at_start {
+            last
+        } else if !ev.shift_key() && active == last {
+            first
+        } else {
+            None
+        };
+        if let Some(el) = wrap_to.and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok()) {
+            ev.prevent_default();
+            _ = el.focus();
+        }
+    };
+
+    let children = store_value(cx, children);
+
+    view! { cx,
+        <Show when=move || show.get() fallback=|_| ()>
+            <div class="modal-backdrop" on:click=on_backdrop_click.clone()>
+                <div
+                    class="modal"
+                    role="dialog"
+                    aria-modal="true"
+                    tabindex="-1"
+                    node_ref=dialog_ref
+                    on:keydown=on_keydown
+                    // Don't let clicks inside the dialog reach the backdrop.
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    {children.with_value(|children| children(cx))}
+                </div>
+            </div>
+        </Show>
+    }
+}
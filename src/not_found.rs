@@ -0,0 +1,48 @@
+use leptos::*;
+use leptos_router::*;
+use std::{cell::Cell, rc::Rc};
+
+/// The HTTP status that the server should respond with, which a page can
+/// change while it's being rendered.
+///
+/// The server provides this as context before rendering. In the browser
+/// there's no response to set a status on, so there's no context either.
+#[derive(Clone)]
+pub struct ResponseStatus(Rc<Cell<u16>>);
+
+impl ResponseStatus {
+    pub fn new() -> Self {
+        Self(Rc::new(Cell::new(200)))
+    }
+
+    pub fn get(&self) -> u16 {
+        self.0.get()
+    }
+
+    pub fn set(&self, status: u16) {
+        self.0.set(status);
+    }
+}
+
+impl Default for ResponseStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The page shown for a URL that doesn't match any route.
+#[component]
+pub fn NotFound(cx: Scope) -> impl IntoView {
+    // So that search engines and link checkers know the page doesn't exist.
+    if let Some(status) = use_context::<ResponseStatus>(cx) {
+        status.set(404);
+    }
+
+    view! { cx,
+        <div class="not-found">
+            <h1>"404"</h1>
+            <p>"There's no page at " <code>{use_location(cx).pathname}</code> "."</p>
+            <A href="/">"Back to the start"</A>
+        </div>
+    }
+}
@@ -0,0 +1,111 @@
+use leptos::*;
+
+/// Moves `n` into `min..=max` if it's outside it.
+fn clamp(n: i32, min: Option<i32>, max: Option<i32>) -> i32 {
+    let n = min.map_or(n, |min| n.max(min));
+    max.map_or(n, |max| n.min(max))
+}
+
+/// Parses typed text as a whole number, clamped to `min..=max`. Returns
+/// `None` if it isn't a whole number.
+fn parse_clamped(text: &str, min: Option<i32>, max: Option<i32>) -> Option<i32> {
+    text.trim().parse().ok().map(|n| clamp(n, min, max))
+}
+
+/// `n` moved by `step` (down, if it's negative), but not past `min..=max`.
+fn stepped(n: i32, step: i32, min: Option<i32>, max: Option<i32>) -> i32 {
+    clamp(n.saturating_add(step), min, max)
+}
+
+/// A number field with buttons to step the value up and down.
+///
+/// Typed values are checked when the field is committed (on Enter or when
+/// it loses focus). Anything that isn't a whole number is rejected and the
+/// field goes back to the current value, and numbers outside `min..=max`
+/// are clamped to it.
+#[component]
+pub fn NumberInput(
+    cx: Scope,
+    /// The current value.
+    value: RwSignal<i32>,
+    #[prop(optional)]
+    /// The lowest value allowed. Unlimited if not set.
+    min: Option<i32>,
+    #[prop(optional)]
+    /// The highest value allowed. Unlimited if not set.
+    max: Option<i32>,
+    #[prop(default = 1)]
+    /// How much the buttons change the value by.
+    step: i32,
+) -> impl IntoView {
+    let at_min = move || min.is_some_and(|min| value.get() <= min);
+    let at_max = move || max.is_some_and(|max| value.get() >= max);
+
+    let on_change = move |ev| match parse_clamped(&event_target_value(&ev), min, max) {
+        Some(n) => value.set(n),
+        // Setting the signal to the value it already had still updates
+        // `prop:value`, which puts the rejected text back.
+        None => value.set(value.get_untracked()),
+    };
+
+    view! { cx,
+        <button
+            type="button"
+            aria-label="Decrease"
+            on:click=move |_| value.update(|n| *n = stepped(*n, -step, min, max))
+            disabled=at_min
+        >
+            "-"
+        </button>
+        <input
+            type="text"
+            inputmode="numeric"
+            size="6"
+            prop:value=move || value.get().to_string()
+            on:change=on_change
+        />
+        <button
+            type="button"
+            aria-label="Increase"
+            on:click=move |_| value.update(|n| *n = stepped(*n, step, min, max))
+            disabled=at_max
+        >
+            "+"
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_clamped_accepts_whole_numbers() {
+        assert_eq!(parse_clamped("42", None, None), Some(42));
+        assert_eq!(parse_clamped(" -7 ", None, None), Some(-7));
+    }
+
+    #[test]
+    fn parse_clamped_rejects_anything_else() {
+        assert_eq!(parse_clamped("", None, None), None);
+        assert_eq!(parse_clamped("abc", None, None), None);
+        assert_eq!(parse_clamped("1.5", None, None), None);
+        assert_eq!(parse_clamped("99999999999", None, None), None);
+    }
+
+    #[test]
+    fn parse_clamped_clamps_to_the_bounds() {
+        assert_eq!(parse_clamped("150", Some(0), Some(100)), Some(100));
+        assert_eq!(parse_clamped("-3", Some(0), Some(100)), Some(0));
+        assert_eq!(parse_clamped("-3", None, Some(100)), Some(-3));
+    }
+
+    #[test]
+    fn stepped_moves_by_step_within_the_bounds() {
+        assert_eq!(stepped(10, 5, Some(0), Some(100)), 15);
+        assert_eq!(stepped(10, -5, Some(0), Some(100)), 5);
+        assert_eq!(stepped(98, 5, Some(0), Some(100)), 100);
+        assert_eq!(stepped(2, -5, Some(0), Some(100)), 0);
+        assert_eq!(stepped(i32::MAX, 1, None, None), i32::MAX);
+    }
+}
@@ -0,0 +1,84 @@
+use leptos::*;
+
+/// How many pages `total` items take up, at `page_size` items per page.
+fn page_count(total: usize, page_size: usize) -> usize {
+    // The last page may be only partly full, so round up.
+    total.div_ceil(page_size.max(1))
+}
+
+/// Buttons for moving between the pages of a list of `total` items.
+///
+/// `current` is the zero-based index of the page being shown.
+#[component]
+pub fn Pagination(
+    cx: Scope,
+    /// How many items there are across all pages.
+    total: usize,
+    /// How many items fit on each page.
+    page_size: usize,
+    /// The page being shown, which is updated when another page is picked.
+    current: RwSignal<usize>,
+) -> impl IntoView {
+    let page_count = page_count(total, page_size);
+
+    let last_page = page_count.saturating_sub(1);
+    let prev = move |_| current.update(|page| *page = page.saturating_sub(1));
+    let next = move |_| current.update(|page| *page = (*page + 1).min(last_page));
+
+    let page_buttons = (0..page_count)
+        .map(|page| {
+            view! { cx,
+                <button
+                    on:click=move |_| current.set(page)
+                    disabled=move || current.get() == page
+                    aria-current=move || (current.get() == page).then_some("page")
+                >
+                    {page + 1}
+                </button>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! { cx,
+        <nav class="pagination">
+            <button on:click=prev disabled=move || current.get() == 0>
+                "Prev"
+            </button>
+            {page_buttons}
+            <button on:click=next disabled=move || current.get() >= last_page>
+                "Next"
+            </button>
+        </nav>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+
+    #[test]
+    fn page_count_rounds_up_to_fit_a_partial_page() {
+        assert_eq!(page_count(20, 10), 2);
+        assert_eq!(page_count(21, 10), 3);
+        assert_eq!(page_count(9, 10), 1);
+        assert_eq!(page_count(0, 10), 0);
+    }
+
+    #[test]
+    fn page_count_treats_a_page_size_of_zero_as_one() {
+        assert_eq!(page_count(3, 0), 3);
+    }
+
+    #[test]
+    fn pagination_shows_a_button_for_each_page() {
+        let html = render_html(|cx| {
+            let current = create_rw_signal(cx, 0);
+            view! { cx, <Pagination total=25 page_size=10 current/> }
+        });
+        // One for each page, plus "Prev" and "Next".
+        assert_eq!(html.matches("<button").count(), 5, "{html}");
+        assert!(html.contains(">3</button>"), "{html}");
+        assert!(!html.contains(">4</button>"), "{html}");
+    }
+}
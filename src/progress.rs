@@ -0,0 +1,894 @@
+use crate::{
+    button::Button,
+    callback::Callback,
+    color_input::ColorInput,
+    document::set_document_title,
+    number_input::NumberInput,
+    reactive_utils::{logged_memo, throttle},
+    shared_counter::{CounterButton, CounterDisplay, CounterProvider},
+    slider::Slider,
+    storage::use_local_storage,
+};
+use leptos::{ev::KeyboardEvent, *};
+use leptos_router::*;
+use std::rc::Rc;
+
+/// Syncs a number with the `key` query parameter of the URL.
+///
+/// Returns the value the URL had on load, or 0 if it was missing or not a
+/// number, along with a callback that writes a new value back to the URL.
+fn use_query_count(cx: Scope, key: &'static str) -> (i32, Callback<i32>) {
+    let query = use_query_map(cx);
+    let location = use_location(cx);
+    let navigate = use_navigate(cx);
+
+    let initial = query.with_untracked(|query| parse_count(query.get(key).map(String::as_str)));
+    let set_query = Callback::from(move |value: i32| {
+        let path = location.pathname.get_untracked();
+        // Any other parameters in the URL are kept as they were.
+        let query = with_query_param(query.get_untracked(), key, value);
+        // Replace the current history entry rather than adding a new one,
+        // so the back button doesn't step through every click.
+        _ = navigate(
+            &format!("{path}{}", query.to_query_string()),
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    });
+
+    (initial, set_query)
+}
+
+/// Parses a count from a query parameter, or 0 if it's missing or not a
+/// number.
+fn parse_count(value: Option<&str>) -> i32 {
+    value.and_then(|value| value.parse().ok()).unwrap_or(0)
+}
+
+/// `query` with `key` set to `value`, and every other parameter unchanged.
+fn with_query_param(mut query: ParamsMap, key: &str, value: i32) -> ParamsMap {
+    query.insert(key.to_string(), value.to_string());
+    query
+}
+
+/// Shows progress toward a goal.
+///
+/// `progress` can be any number type that converts losslessly to `f64`
+/// (e.g., `i32`, `u16` or `f64` itself), so fractional values like `33.7`
+/// fill the bar smoothly. The percentage shown with `show_percent` is
+/// rounded to the nearest whole number.
+#[component]
+pub fn ProgressBar<P>(
+    // All components take a reactive `Scope` as the first argument
+    cx: Scope,
+    // Marks this as an optional prop. It will default to the default
+    // value of its type, i.e., 0.
+    #[prop(default = 100)]
+    /// The maximum value of the progress bar.
+    max: u16,
+    #[prop(default = 0)]
+    /// The value at which the progress bar starts to fill.
+    min: u16,
+    // Will run `.into()` on the value passed into the prop.
+    #[prop(into)]
+    // `Signal<T>` is a wrapper for several reactive types.
+    // It can be helpful in component APIs like this, where we
+    // might want to take any kind of reactive value
+    /// How much progress should be displayed.
+    progress: Signal<P>,
+    #[prop(optional)]
+    /// Whether to render the current percentage next to the bar.
+    show_percent: bool,
+    #[prop(optional)]
+    /// Pairs of `(percentage, class)`. The class with the highest
+    /// percentage that has been reached is applied to the bar.
+    thresholds: Vec<(u8, &'static str)>,
+    // `MaybeSignal<T>` is like `Signal<T>`, but can also hold a plain
+    // value, which lets it implement `Default` for optional props.
+    #[prop(optional, into)]
+    /// Whether the amount of progress is unknown. While this is `true`,
+    /// the bar shows the browser's indeterminate animation and `max` and
+    /// `progress` are ignored.
+    indeterminate: MaybeSignal<bool>,
+    #[prop(optional, into)]
+    /// Called once each time `progress` reaches `max`.
+    on_complete: Option<Callback<()>>,
+    #[prop(optional, into)]
+    /// A name for the bar that's read out by screen readers.
+    label: Option<String>,
+    #[prop(default = false)]
+    /// Whether to draw the bar with `<div>`s whose width animates, rather
+    /// than with the native `<progress>` element, which jumps straight to
+    /// each new value. The `<div>`s only get the same `aria-*` attributes,
+    /// not any other built-in accessibility of `<progress>`. An
+    /// indeterminate animated bar is drawn empty.
+    animate: bool,
+    #[prop(optional, into)]
+    /// Extra classes for the bar, e.g., to position it. These are added
+    /// alongside the bar's own classes rather than replacing them, so the
+    /// threshold classes still apply.
+    class: Option<String>,
+    #[prop(optional, into)]
+    /// Inline styles for the bar. For an animated bar, these go on the
+    /// outer `<div>`, so they can't override the width of the fill.
+    style: Option<String>,
+    #[prop(optional)]
+    /// Text to show after the bar, e.g., "37 / 50 MB". It's called with the
+    /// current value (clamped to `min..=max`) and `max` each time the value
+    /// changes, and screen readers read it out instead of the bare number.
+    ///
+    /// Pass a boxed closure, e.g.,
+    /// `caption=Box::new(|value, max| format!("{value} / {max} MB"))`.
+    caption: Option<Box<dyn Fn(f64, f64) -> String>>,
+    #[prop(optional, into)]
+    /// The color of the fill, as any CSS color. This only applies to an
+    /// animated bar, and takes the place of the `thresholds` colors.
+    color: Option<Signal<String>>,
+) -> impl IntoView
+where
+    P: Into<f64> + Copy + 'static,
+{
+    // The native `<progress>` element always starts at 0, so we shift
+    // everything down by `min`. If `min > max` the range is empty.
+    let range = f64::from(max.saturating_sub(min));
+    // Browsers don't all handle a `value` outside `0..=max` the same way, so
+    // we clamp it here. `progress` itself is left alone.
+    let value = move || progress_value(progress.get().into(), min, max);
+
+    // An out-of-range value is usually a bug in the caller, so point it out
+    // while developing, but only once rather than on every change.
+    if cfg!(debug_assertions) {
+        create_effect(cx, move |warned: Option<bool>| {
+            if warned == Some(true) {
+                return true;
+            }
+            let progress = progress.get().into();
+            let in_range = (f64::from(min)..=f64::from(max)).contains(&progress);
+            if !in_range {
+                warn!(
+                    "`ProgressBar` progress {progress} is outside {min}..={max}, and was clamped"
+                );
+            }
+            !in_range
+        });
+    }
+    let percent = move || {
+        if range == 0.0 {
+            0.0
+        } else {
+            (value() / range * 100.0).round()
+        }
+    };
+    let threshold_class = move || threshold_class(&thresholds, percent());
+    let class = class.unwrap_or_default();
+
+    if let Some(on_complete) = on_complete {
+        // An effect receives the value it returned last time it ran, so we
+        // can use it to remember whether we were already complete. That way
+        // the callback only fires on the transition to complete.
+        create_effect(cx, move |was_complete: Option<bool>| {
+            let is_complete = progress.get().into() >= f64::from(max);
+            if is_complete && !was_complete.unwrap_or(false) {
+                on_complete.call(());
+            }
+            is_complete
+        });
+    }
+
+    // Screen readers treat a missing `aria-valuenow` as indeterminate.
+    let aria_valuenow = move || (!indeterminate.get()).then(|| value() + f64::from(min));
+    // Both the visible caption and `aria-valuetext` need to call `caption`,
+    // so it's shared through an `Rc`.
+    let caption: Option<Rc<dyn Fn(f64, f64) -> String>> = caption.map(Rc::from);
+    let has_caption = caption.is_some();
+    let caption_text = move || {
+        caption
+            .as_ref()
+            .filter(|_| !indeterminate.get())
+            .map(|caption| caption(value() + f64::from(min), f64::from(max)))
+    };
+
+    let bar = if animate {
+        // Unlike `percent`, this isn't rounded, so the bar moves smoothly.
+        let width = move || {
+            if range == 0.0 || indeterminate.get() {
+                0.0
+            } else {
+                value() / range * 100.0
+            }
+        };
+        view! { cx,
+            <div
+                class=move || {
+                    class_list(&["animated-progress", threshold_class().unwrap_or_default(), &class])
+                }
+                style=style
+                role="progressbar"
+                aria-label=label
+                aria-valuemin=min
+                aria-valuemax=max
+                aria-valuenow=aria_valuenow
+                aria-valuetext=caption_text.clone()
+            >
+                // The `transition` on this in `index.html` animates each
+                // change of width.
+                <div style=move || {
+                    let color = color
+                        .map(|color| format!("; background: {}", color.get()))
+                        .unwrap_or_default();
+                    format!("width: {}%{color}", width())
+                }/>
+            </div>
+        }
+        .into_view(cx)
+    } else {
+        view! { cx,
+            <progress
+                max={range}
+                // Returning `None` removes the attribute entirely, which is
+                // what puts a `<progress>` into its indeterminate state.
+                value={move || (!indeterminate.get()).then(value)}
+                class=move || class_list(&[threshold_class().unwrap_or_default(), &class])
+                style=style
+                // `<progress>` already has this role, but some screen readers
+                // only announce the value when the `aria-*` attributes are set.
+                role="progressbar"
+                aria-label=label
+                aria-valuemin=min
+                aria-valuemax=max
+                aria-valuenow=aria_valuenow
+                aria-valuetext=caption_text.clone()
+            />
+        }
+        .into_view(cx)
+    };
+
+    view! { cx,
+        {bar}
+        // This needs to be a closure so the text updates with `progress`.
+        {show_percent.then(|| view! { cx, <span>{move || format!("{}%", percent())}</span> })}
+        {has_caption.then(|| view! { cx, " " <span class="progress-caption">{caption_text}</span> })}
+        <br/>
+    }
+}
+
+/// The `value` of a `<progress>` showing `progress` between `min` and
+/// `max`, shifted down so it starts at 0 and clamped to fit.
+fn progress_value(progress: f64, min: u16, max: u16) -> f64 {
+    let value = progress - f64::from(min);
+    // `NaN` would render as an invalid `value` attribute.
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, f64::from(max.saturating_sub(min)))
+    }
+}
+
+/// The class from `thresholds` with the highest percentage that `percent`
+/// has reached, if it's reached any.
+fn threshold_class(thresholds: &[(u8, &'static str)], percent: f64) -> Option<&'static str> {
+    thresholds
+        .iter()
+        .filter(|(floor, _)| f64::from(*floor) <= percent)
+        .max_by_key(|(floor, _)| *floor)
+        .map(|(_, class)| *class)
+}
+
+/// Joins the non-empty `classes` into a `class` attribute.
+fn class_list(classes: &[&str]) -> String {
+    classes
+        .iter()
+        .filter(|class| !class.is_empty())
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A progress bar split into several colored segments, e.g., one for each
+/// stage of a pipeline.
+///
+/// The native `<progress>` element can only show one fill, so this is built
+/// from `<div>`s instead. Segments fill in order, and a segment that
+/// `progress` is partway through is only partly filled.
+#[component]
+pub fn SegmentedProgressBar(
+    cx: Scope,
+    /// Pairs of `(length, color)`. The bar's maximum is the sum of the
+    /// lengths, and `color` can be any CSS color.
+    segments: Vec<(u16, &'static str)>,
+    #[prop(into)]
+    /// How much progress should be displayed.
+    progress: Signal<i32>,
+) -> impl IntoView {
+    let total: u32 = segments.iter().map(|(length, _)| u32::from(*length)).sum();
+
+    // Each segment starts where the previous one ended.
+    let mut start = 0;
+    let segments = segments
+        .into_iter()
+        .map(|(length, color)| {
+            let segment_start = start;
+            start += u32::from(length);
+            let width = if total == 0 {
+                0.0
+            } else {
+                f64::from(length) / f64::from(total) * 100.0
+            };
+            // How much of this segment is filled, as a percentage of it.
+            let fill = move || {
+                if length == 0 {
+                    return 0.0;
+                }
+                let into_segment = f64::from(progress.get()) - f64::from(segment_start);
+                (into_segment / f64::from(length) * 100.0).clamp(0.0, 100.0)
+            };
+
+            view! { cx,
+                <div style=format!("width: {width}%")>
+                    <div style=move || format!("width: {}%; background: {color}", fill())/>
+                </div>
+            }
+        })
+        .collect_view(cx);
+
+    view! { cx,
+        <div class="segmented-progress">{segments}</div>
+    }
+}
+
+/// A counter that can be incremented, decremented and reset, along with
+/// a few progress bars that track its value.
+#[component]
+pub fn Counter(
+    cx: Scope,
+    /// The value to start from, and to return to when reset.
+    initial: i32,
+    #[prop(default = 1)]
+    /// How much each click changes the value by.
+    step: i32,
+    #[prop(optional)]
+    /// The lowest value the counter can reach.
+    min: Option<i32>,
+    #[prop(optional)]
+    /// The highest value the counter can reach.
+    max: Option<i32>,
+    #[prop(optional, into)]
+    /// Called with the new value whenever it changes.
+    on_change: Option<Callback<i32>>,
+    #[prop(optional)]
+    /// If set, the value is saved to `localStorage` under this key.
+    storage_key: Option<&'static str>,
+) -> impl IntoView {
+    let clamp = move |n: i32| {
+        let n = min.map_or(n, |min| n.max(min));
+        max.map_or(n, |max| n.min(max))
+    };
+    let (count, set_count) = match storage_key {
+        Some(key) => {
+            let (count, set_count) = use_local_storage(cx, key, clamp(initial));
+            // The saved value may be out of bounds if they've changed since
+            // it was saved, or if it was edited by hand.
+            let saved = count.get_untracked();
+            if clamp(saved) != saved {
+                set_count.set(clamp(saved));
+            }
+            (count, set_count)
+        }
+        None => {
+            let (count, set_count) = create_signal(cx, clamp(initial));
+            (count.into(), set_count)
+        }
+    };
+    // A plain closure like `move || count.get() * 2` would redo the work
+    // every time it's read. A memo only reruns when `count` changes, and
+    // every reader shares the cached result, so `logged_memo` only logs
+    // once per change even though `double_count` is read in several places.
+    let double_count = logged_memo(cx, "double_count", move || count.get() * 2);
+
+    let at_min = move || min.is_some_and(|min| count.get() <= min);
+    let at_max = move || max.is_some_and(|max| count.get() >= max);
+
+    let increment = move || set_count.update(|n| *n = clamp(n.saturating_add(step)));
+    let decrement = move || set_count.update(|n| *n = clamp(n.saturating_sub(step)));
+
+    let on_keydown = move |ev: KeyboardEvent| match ev.key().as_str() {
+        "ArrowUp" | "ArrowRight" => {
+            // Stop the arrow keys from scrolling the page.
+            ev.prevent_default();
+            increment();
+        }
+        "ArrowDown" | "ArrowLeft" => {
+            ev.prevent_default();
+            decrement();
+        }
+        _ => {}
+    };
+
+    if let Some(on_change) = on_change {
+        // Effects also run as soon as they're created, before anything has
+        // changed, so the first run only remembers the starting value.
+        create_effect(cx, move |prev: Option<i32>| {
+            let count = count.get();
+            if prev.is_some_and(|prev| prev != count) {
+                on_change.call(count);
+            }
+            count
+        });
+    }
+
+    view! { cx,
+        // `tabindex` makes the container focusable, so it can
+        // receive key presses.
+        <div tabindex="0" on:keydown=on_keydown>
+            <Button
+                on_click=move |_| increment()
+                disabled=Signal::derive(cx, at_max)
+                class=Signal::derive(cx, move || {
+                    if count.get() % 2 == 1 { "red" } else { "" }.to_string()
+                })
+            >
+                "Click me"
+            </Button>
+            <Button on_click=move |_| decrement() disabled=Signal::derive(cx, at_min)>
+                "Decrement"
+            </Button>
+            <Button on_click=move |_| set_count.set(clamp(initial))>
+                "Reset"
+            </Button>
+        </div>
+        <ProgressBar
+            max=50
+            progress=count
+            on_complete=|_| log!("Reached 50!")
+        />
+        <ProgressBar progress=count show_percent=true/>
+        <ProgressBar max=50 progress=double_count/>
+        <ProgressBar
+            max=20
+            progress=count
+            thresholds=vec![(70, "yellow"), (90, "red")]
+        />
+        <ProgressBar
+            progress=count
+            indeterminate=Signal::derive(cx, move || count.get() == 0)
+        />
+    }
+}
+
+/// Calls `log` with `count` whenever it changes, but only while `enabled`
+/// is true.
+fn log_count_while(
+    cx: Scope,
+    enabled: ReadSignal<bool>,
+    count: ReadSignal<i32>,
+    log: impl Fn(i32) + 'static,
+) {
+    // An effect can't be removed once it's created; it lives as long as its
+    // scope. To turn one "off", it can check a signal and return early.
+    // Effects track whatever they read on their latest run, so while this
+    // returns before reading `count`, changing `count` doesn't rerun it at
+    // all, and only `enabled` does.
+    create_effect(cx, move |_| {
+        if !enabled.get() {
+            return;
+        }
+        log(count.get());
+    });
+}
+
+#[component]
+pub fn AppOne(cx: Scope) -> impl IntoView {
+    let slider_value = create_rw_signal(cx, 25);
+    let bar_color = create_rw_signal(cx, "#4682b4".to_string());
+    let (count, set_count) = create_signal(cx, 0);
+    set_document_title(cx, move || format!("Count: {}", count.get()));
+
+    let (log_changes, set_log_changes) = create_signal(cx, false);
+    log_count_while(cx, log_changes, count, |count| log!("count is {count}"));
+    // The bar below only catches up with the slider every half second.
+    let throttled_value = throttle(cx, slider_value.read_only(), 500);
+    let (initial_count, set_query_count) = use_query_count(cx, "count");
+    let third = Signal::derive(cx, move || f64::from(slider_value.get()) / 3.0);
+
+    view! { cx,
+        <Counter initial=0 storage_key="count" on_change=move |n| set_count.set(n)/>
+        <label>
+            <input
+                type="checkbox"
+                prop:checked=move || log_changes.get()
+                on:change=move |ev| set_log_changes.set(event_target_checked(&ev))
+            />
+            "Log changes to the console"
+        </label>
+        <h2>"Shared Counter"</h2>
+        // Neither of these takes any props: they find the count through the
+        // context that `CounterProvider` sets up.
+        <CounterProvider>
+            <CounterDisplay/>
+            <CounterButton/>
+        </CounterProvider>
+        // Outside the provider, there's no context to find.
+        <CounterDisplay/>
+        <h2>"Counter in the URL"</h2>
+        <Counter initial=initial_count on_change=set_query_count/>
+        <h2>"Slider"</h2>
+        <Slider value=slider_value min=0 max=100/>
+        " "
+        <NumberInput value=slider_value min=0 max=100 step=5/>
+        <ProgressBar
+            progress=slider_value
+            show_percent=true
+            label="Slider value"
+            caption=Box::new(|value, max| format!("{value} / {max} MB"))
+        />
+        <p>"In stages:"</p>
+        <SegmentedProgressBar
+            segments=vec![(20, "steelblue"), (50, "seagreen"), (30, "goldenrod")]
+            progress=slider_value
+        />
+        <p>"Animated:"</p>
+        <ProgressBar progress=slider_value animate=true style="max-width: 300px"/>
+        <p>"In any color:"</p>
+        <ColorInput value=bar_color/>
+        <br/>
+        <ProgressBar progress=slider_value animate=true color=bar_color/>
+        <p>"Throttled:"</p>
+        <ProgressBar progress=throttled_value show_percent=true/>
+        <p>"A third of the slider, as a fraction:"</p>
+        <ProgressBar progress=third show_percent=true/>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+    use std::cell::Cell;
+
+    const THRESHOLDS: &[(u8, &str)] = &[(70, "yellow"), (90, "red")];
+
+    #[test]
+    fn threshold_class_switches_exactly_at_each_boundary() {
+        assert_eq!(threshold_class(THRESHOLDS, 69.0), None);
+        assert_eq!(threshold_class(THRESHOLDS, 70.0), Some("yellow"));
+        assert_eq!(threshold_class(THRESHOLDS, 71.0), Some("yellow"));
+        assert_eq!(threshold_class(THRESHOLDS, 89.0), Some("yellow"));
+        assert_eq!(threshold_class(THRESHOLDS, 90.0), Some("red"));
+        assert_eq!(threshold_class(THRESHOLDS, 91.0), Some("red"));
+    }
+
+    #[test]
+    fn threshold_class_ignores_the_order_thresholds_are_given_in() {
+        assert_eq!(
+            threshold_class(&[(90, "red"), (70, "yellow")], 95.0),
+            Some("red")
+        );
+    }
+
+    #[test]
+    fn progress_value_clamps_to_the_range() {
+        assert_eq!(progress_value(999.0, 0, 50), 50.0);
+        assert_eq!(progress_value(-5.0, 0, 50), 0.0);
+        assert_eq!(progress_value(25.5, 0, 50), 25.5);
+        assert_eq!(progress_value(f64::NAN, 0, 50), 0.0);
+    }
+
+    #[test]
+    fn progress_value_starts_from_min() {
+        assert_eq!(progress_value(30.0, 20, 50), 10.0);
+        assert_eq!(progress_value(10.0, 20, 50), 0.0);
+        // `min > max` leaves an empty range.
+        assert_eq!(progress_value(30.0, 50, 20), 0.0);
+    }
+
+    #[test]
+    fn progress_bar_renders_out_of_range_progress_clamped() {
+        let html = leptos::ssr::render_to_string(|cx| {
+            view! { cx, <ProgressBar max=50 progress=Signal::derive(cx, || 999)/> }
+        });
+        assert!(html.contains(r#"value="50""#), "{html}");
+    }
+
+    #[test]
+    fn parse_count_reads_a_number() {
+        assert_eq!(parse_count(Some("42")), 42);
+        assert_eq!(parse_count(Some("-3")), -3);
+    }
+
+    #[test]
+    fn parse_count_falls_back_to_zero() {
+        assert_eq!(parse_count(None), 0);
+        assert_eq!(parse_count(Some("")), 0);
+        assert_eq!(parse_count(Some("ten")), 0);
+        assert_eq!(parse_count(Some("1.5")), 0);
+    }
+
+    #[test]
+    fn with_query_param_keeps_other_parameters() {
+        let mut query = ParamsMap::new();
+        query.insert("tab".to_string(), "two".to_string());
+        query.insert("count".to_string(), "1".to_string());
+
+        let query = with_query_param(query, "count", 5);
+        assert_eq!(query.get("count").map(String::as_str), Some("5"));
+        assert_eq!(query.get("tab").map(String::as_str), Some("two"));
+    }
+
+    #[test]
+    fn progress_bar_renders_the_threshold_class() {
+        let html = leptos::ssr::render_to_string(|cx| {
+            view! { cx, <ProgressBar max=20 progress=Signal::derive(cx, || 14) thresholds=THRESHOLDS.to_vec()/> }
+        });
+        assert!(html.contains(r#"class="yellow""#), "{html}");
+    }
+
+    #[test]
+    fn class_list_skips_empty_classes() {
+        assert_eq!(class_list(&["", "yellow", "", "wide"]), "yellow wide");
+        assert_eq!(class_list(&["", ""]), "");
+    }
+
+    #[test]
+    fn progress_bar_renders_its_class_and_style() {
+        let html = leptos::ssr::render_to_string(|cx| {
+            view! { cx,
+                <ProgressBar
+                    max=20
+                    progress=Signal::derive(cx, || 14)
+                    thresholds=THRESHOLDS.to_vec()
+                    class="wide"
+                    style="height: 2em"
+                />
+            }
+        });
+        assert!(html.contains(r#"class="yellow wide""#), "{html}");
+        assert!(html.contains(r#"style="height: 2em""#), "{html}");
+    }
+
+    #[test]
+    fn progress_bar_renders_its_aria_values() {
+        let html = render_html(|cx| {
+            view! { cx, <ProgressBar min=10 max=50 progress=Signal::derive(cx, || 30)/> }
+        });
+        assert!(
+            html.contains(r#"aria-valuemin="10" aria-valuemax="50" aria-valuenow="30""#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn indeterminate_progress_bar_leaves_out_aria_valuenow() {
+        for animate in [false, true] {
+            let html = render_html(move |cx| {
+                view! { cx,
+                    <ProgressBar
+                        progress=Signal::derive(cx, || 30)
+                        indeterminate=true
+                        animate=animate
+                    />
+                }
+            });
+            assert!(html.contains("aria-valuemax"), "{html}");
+            assert!(!html.contains("aria-valuenow"), "{html}");
+        }
+    }
+
+    #[test]
+    fn count_is_only_logged_while_logging_is_on() {
+        let logs = Rc::new(Cell::new(0));
+        let runtime = create_runtime();
+        create_scope(runtime, {
+            let logs = Rc::clone(&logs);
+            move |cx| {
+                let (enabled, set_enabled) = create_signal(cx, false);
+                let (count, set_count) = create_signal(cx, 0);
+                let log = {
+                    let logs = Rc::clone(&logs);
+                    move |_| logs.set(logs.get() + 1)
+                };
+                log_count_while(cx, enabled, count, log);
+
+                set_count.set(1);
+                assert_eq!(logs.get(), 0);
+
+                // Turning logging on logs the current count straight away.
+                set_enabled.set(true);
+                assert_eq!(logs.get(), 1);
+                set_count.set(2);
+                set_count.set(3);
+                assert_eq!(logs.get(), 3);
+
+                set_enabled.set(false);
+                set_count.set(4);
+                assert_eq!(logs.get(), 3);
+            }
+        })
+        .dispose();
+        runtime.dispose();
+    }
+
+    #[test]
+    fn counter_starts_within_its_bounds() {
+        let html = render_html(|cx| view! { cx, <Counter initial=100 min=5 max=10/> });
+        assert!(html.contains(r#"<progress max="50" value="10""#), "{html}");
+        let html = render_html(|cx| view! { cx, <Counter initial=0 min=5 max=10/> });
+        assert!(html.contains(r#"<progress max="50" value="5""#), "{html}");
+    }
+
+    #[test]
+    fn counter_disables_the_buttons_at_its_bounds() {
+        let at_max = render_html(|cx| view! { cx, <Counter initial=10 min=5 max=10/> });
+        assert!(
+            at_max.contains(r#"disabled aria-busy="false">Click me"#),
+            "{at_max}"
+        );
+        assert!(
+            at_max.contains(r#"<button type="button" aria-busy="false">Decrement"#),
+            "{at_max}"
+        );
+
+        let at_min = render_html(|cx| view! { cx, <Counter initial=6 min=6 max=10/> });
+        assert!(
+            at_min.contains(r#"<button type="button" aria-busy="false">Click me"#),
+            "{at_min}"
+        );
+        assert!(
+            at_min.contains(r#"disabled aria-busy="false">Decrement"#),
+            "{at_min}"
+        );
+
+        // Without bounds, neither is ever disabled.
+        let unbounded = render_html(|cx| view! { cx, <Counter initial=0/> });
+        assert!(!unbounded.contains("disabled"), "{unbounded}");
+    }
+
+    #[test]
+    fn counter_marks_odd_counts_red() {
+        let odd = render_html(|cx| view! { cx, <Counter initial=3/> });
+        assert!(
+            odd.contains(r#"<button type="button" class="red" aria-busy="false">Click me"#),
+            "{odd}"
+        );
+        let even = render_html(|cx| view! { cx, <Counter initial=4/> });
+        assert!(!even.contains(r#"class="red""#), "{even}");
+    }
+
+    #[test]
+    fn counter_shows_its_value_in_the_progress_bars() {
+        let html = leptos::ssr::render_to_string(|cx| view! { cx, <Counter initial=7/> });
+        // The bar out of 50, and the one showing `double_count`.
+        assert!(html.contains(r#"max="50" value="7""#), "{html}");
+        assert!(html.contains(r#"max="50" value="14""#), "{html}");
+    }
+
+    #[test]
+    fn counter_does_not_call_on_change_before_anything_changes() {
+        let calls = Rc::new(Cell::new(0));
+        render_html({
+            let calls = Rc::clone(&calls);
+            move |cx| {
+                let on_change = move |_| calls.set(calls.get() + 1);
+                view! { cx, <Counter initial=3 on_change=on_change/> }
+            }
+        });
+        assert_eq!(calls.get(), 0);
+    }
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use crate::test_utils::{button, mount};
+    use std::cell::{Cell, RefCell};
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn clicking_the_counter_fills_the_progress_bar_and_toggles_red() {
+        // `AppOne`'s counter is saved, so start from 0 whatever ran before.
+        if let Ok(Some(storage)) = window().local_storage() {
+            _ = storage.remove_item("count");
+        }
+        let root = mount(|cx| view! { cx, <Router><AppOne/></Router> });
+
+        // The first `<progress>` is the one that counts up to 50.
+        let button = button(&root, "Click me");
+        let progress = || {
+            root.query_selector("progress")
+                .unwrap()
+                .unwrap()
+                .get_attribute("value")
+        };
+        let is_red = || {
+            button
+                .get_attribute("class")
+                .is_some_and(|class| class.split_whitespace().any(|class| class == "red"))
+        };
+
+        assert_eq!(progress().as_deref(), Some("0"));
+        assert!(!is_red());
+
+        button.click();
+        assert_eq!(progress().as_deref(), Some("1"));
+        assert!(is_red());
+
+        button.click();
+        assert_eq!(progress().as_deref(), Some("2"));
+        assert!(!is_red());
+    }
+
+    #[wasm_bindgen_test]
+    fn counter_steps_up_to_its_max_and_then_disables() {
+        let root = mount(|cx| view! { cx, <Counter initial=0 step=5 max=12/> });
+        let increment = button(&root, "Click me");
+        let decrement = button(&root, "Decrement");
+        let value = || {
+            root.query_selector("progress")
+                .unwrap()
+                .unwrap()
+                .get_attribute("value")
+        };
+
+        increment.click();
+        assert_eq!(value().as_deref(), Some("5"));
+        increment.click();
+        increment.click();
+        assert_eq!(value().as_deref(), Some("12"));
+        assert!(increment.has_attribute("disabled"));
+
+        decrement.click();
+        assert_eq!(value().as_deref(), Some("7"));
+        assert!(!increment.has_attribute("disabled"));
+    }
+
+    #[wasm_bindgen_test]
+    fn counter_calls_on_change_with_each_new_value() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let root = mount({
+            let changes = Rc::clone(&changes);
+            move |cx| {
+                let on_change = move |n| changes.borrow_mut().push(n);
+                view! { cx, <Counter initial=0 on_change=on_change/> }
+            }
+        });
+        assert!(changes.borrow().is_empty());
+
+        button(&root, "Click me").click();
+        button(&root, "Click me").click();
+        button(&root, "Decrement").click();
+        assert_eq!(*changes.borrow(), [1, 2, 1]);
+    }
+
+    #[wasm_bindgen_test]
+    fn progress_bar_updates_aria_valuenow_as_progress_changes() {
+        let progress = Rc::new(Cell::new(None));
+        let root = mount({
+            let progress = Rc::clone(&progress);
+            move |cx| {
+                let value = create_rw_signal(cx, 10);
+                progress.set(Some(value));
+                view! { cx, <ProgressBar min=10 max=50 progress=value/> }
+            }
+        });
+        let progress = progress.get().unwrap();
+        let bar = root.query_selector("[role=progressbar]").unwrap().unwrap();
+        let aria = |name: &str| bar.get_attribute(name);
+
+        assert_eq!(aria("aria-valuemin").as_deref(), Some("10"));
+        assert_eq!(aria("aria-valuemax").as_deref(), Some("50"));
+        assert_eq!(aria("aria-valuenow").as_deref(), Some("10"));
+
+        progress.set(30);
+        assert_eq!(aria("aria-valuenow").as_deref(), Some("30"));
+        // Like `value`, it's clamped to the bar's range.
+        progress.set(70);
+        assert_eq!(aria("aria-valuenow").as_deref(), Some("50"));
+        assert_eq!(aria("aria-valuemin").as_deref(), Some("10"));
+        assert_eq!(aria("aria-valuemax").as_deref(), Some("50"));
+    }
+}
@@ -0,0 +1,84 @@
+use leptos::{ev::KeyboardEvent, html::Div, *};
+use wasm_bindgen::JsCast;
+
+/// A set of radio buttons, of which only one can be selected, bound to the
+/// value of the selected one.
+#[component]
+pub fn RadioGroup(
+    cx: Scope,
+    /// Groups the radio buttons together, so the browser only lets one of
+    /// them be checked. It should be unique on the page.
+    name: &'static str,
+    /// Pairs of `(value, label)`, one for each radio button.
+    options: Vec<(String, String)>,
+    /// The value of the selected option. If it doesn't match any of them,
+    /// none are checked.
+    value: RwSignal<String>,
+) -> impl IntoView {
+    let values = store_value(
+        cx,
+        options
+            .iter()
+            .map(|(value, _)| value.clone())
+            .collect::<Vec<_>>(),
+    );
+
+    let group: NodeRef<Div> = create_node_ref(cx);
+    let select = move |index: usize| {
+        value.set(values.with_value(|values| values[index].clone()));
+        // As in the ARIA radio group pattern, focus follows the selection.
+        if let Some(radio) = group
+            .get()
+            .and_then(|group| group.query_selector_all("input").ok())
+            .and_then(|radios| radios.item(index as u32))
+            .and_then(|radio| radio.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            _ = radio.focus();
+        }
+    };
+    // Browsers already move between radios with the same `name` using the
+    // arrow keys, but not all of them wrap around at the ends, so we handle
+    // the keys ourselves to make them behave the same everywhere.
+    let on_keydown = move |ev: KeyboardEvent| {
+        let count = values.with_value(Vec::len);
+        if count == 0 {
+            return;
+        }
+        let current = values.with_value(|values| {
+            value.with_untracked(|value| values.iter().position(|v| v == value))
+        });
+        match (ev.key().as_str(), current) {
+            ("ArrowDown" | "ArrowRight", Some(current)) => select((current + 1) % count),
+            ("ArrowUp" | "ArrowLeft", Some(current)) => select((current + count - 1) % count),
+            ("ArrowDown" | "ArrowRight", None) => select(0),
+            ("ArrowUp" | "ArrowLeft", None) => select(count - 1),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    let radios = options
+        .into_iter()
+        .enumerate()
+        .map(|(index, (option_value, label))| {
+            let checked = move || value.with(|value| *value == option_value);
+            view! { cx,
+                <label>
+                    <input
+                        type="radio"
+                        name=name
+                        prop:checked=checked
+                        on:change=move |_| select(index)
+                    />
+                    {label}
+                </label>
+            }
+        })
+        .collect_view(cx);
+
+    view! { cx,
+        <div role="radiogroup" node_ref=group on:keydown=on_keydown>
+            {radios}
+        </div>
+    }
+}
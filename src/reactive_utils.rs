@@ -0,0 +1,703 @@
+use leptos::*;
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
+/// Combines two signals into one that holds both of their values, and
+/// updates whenever either of them changes.
+pub fn zip<A, B>(cx: Scope, a: Signal<A>, b: Signal<B>) -> Signal<(A, B)>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+{
+    Signal::derive(cx, move || (a.get(), b.get()))
+}
+
+/// Derives a signal from two others, rerunning `f` whenever either of
+/// them changes.
+///
+/// This saves writing out a closure that reads each signal by hand, e.g.,
+/// `map2(cx, a, b, |a, b| a + b)` instead of
+/// `Signal::derive(cx, move || a.get() + b.get())`.
+pub fn map2<A, B, C>(
+    cx: Scope,
+    a: Signal<A>,
+    b: Signal<B>,
+    f: impl Fn(A, B) -> C + 'static,
+) -> Signal<C>
+where
+    A: Clone + 'static,
+    B: Clone + 'static,
+    C: Clone + 'static,
+{
+    let both = zip(cx, a, b);
+    Signal::derive(cx, move || {
+        let (a, b) = both.get();
+        f(a, b)
+    })
+}
+
+/// A memo that logs to the console each time it reruns `f`, and again when
+/// that gives a different value from last time.
+///
+/// This makes it easy to watch how memos behave: `f` reruns whenever a
+/// signal it reads changes, but if the result is equal to the old one, it
+/// isn't "changed", and nothing that reads the memo runs again.
+pub fn logged_memo<T>(cx: Scope, label: &str, f: impl Fn() -> T + 'static) -> Memo<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    logged_memo_with(cx, label, f, |message| log!("{message}"))
+}
+
+/// [`logged_memo`], but passing each message to `log` instead of the
+/// console.
+fn logged_memo_with<T>(
+    cx: Scope,
+    label: &str,
+    f: impl Fn() -> T + 'static,
+    log: impl Fn(String) + 'static,
+) -> Memo<T>
+where
+    T: PartialEq + Clone + 'static,
+{
+    let label = label.to_string();
+    create_memo(cx, move |prev: Option<&T>| {
+        let value = f();
+        log(format!("{label} recomputed"));
+        if prev != Some(&value) {
+            log(format!("{label} changed"));
+        }
+        value
+    })
+}
+
+/// Where the timing helpers below get their timeouts and intervals from.
+///
+/// In the app that's always the browser, through [`BrowserTimers`]. Tests
+/// use a fake instead, so they can move time forward themselves.
+trait Timers: Copy + 'static {
+    type Timeout: Clone + 'static;
+    type Interval: Clone + 'static;
+
+    /// Calls `f` once `delay` has passed, unless it's cleared first.
+    fn set_timeout(self, f: impl FnOnce() + 'static, delay: Duration) -> Option<Self::Timeout>;
+
+    /// Cancels a timeout, if it hasn't fired yet.
+    fn clear_timeout(self, handle: Self::Timeout);
+
+    /// Calls `f` every time `delay` passes, until it's cleared.
+    fn set_interval(self, f: impl Fn() + 'static, delay: Duration) -> Option<Self::Interval>;
+
+    /// Stops an interval.
+    fn clear_interval(self, handle: Self::Interval);
+}
+
+/// The browser's `setTimeout` and `setInterval`.
+#[derive(Clone, Copy)]
+struct BrowserTimers;
+
+impl Timers for BrowserTimers {
+    type Timeout = TimeoutHandle;
+    type Interval = IntervalHandle;
+
+    fn set_timeout(self, f: impl FnOnce() + 'static, delay: Duration) -> Option<TimeoutHandle> {
+        set_timeout_with_handle(f, delay).ok()
+    }
+
+    fn clear_timeout(self, handle: TimeoutHandle) {
+        handle.clear();
+    }
+
+    fn set_interval(self, f: impl Fn() + 'static, delay: Duration) -> Option<IntervalHandle> {
+        set_interval_with_handle(f, delay).ok()
+    }
+
+    fn clear_interval(self, handle: IntervalHandle) {
+        handle.clear();
+    }
+}
+
+/// Returns a signal that follows `source`, but only updates once `source`
+/// has stopped changing for `ms` milliseconds.
+pub fn debounce<T: Clone + 'static>(cx: Scope, source: ReadSignal<T>, ms: i32) -> ReadSignal<T> {
+    debounce_with(cx, source, ms, BrowserTimers)
+}
+
+fn debounce_with<T: Clone + 'static>(
+    cx: Scope,
+    source: ReadSignal<T>,
+    ms: i32,
+    timers: impl Timers,
+) -> ReadSignal<T> {
+    let (debounced, set_debounced) = create_signal(cx, source.get_untracked());
+    let pending = store_value(cx, None);
+    let delay = Duration::from_millis(ms.max(0) as u64);
+
+    create_effect(cx, move |prev: Option<()>| {
+        let value = source.get();
+        // The effect runs once immediately; there's nothing to delay yet.
+        if prev.is_none() {
+            return;
+        }
+        // Each new value cancels the previous timeout, so only the last one
+        // in a burst of changes gets through.
+        if let Some(handle) = pending.get_value() {
+            timers.clear_timeout(handle);
+        }
+        let handle = timers.set_timeout(move || set_debounced.set(value), delay);
+        pending.set_value(handle);
+    });
+
+    // Don't let a pending update fire after the component is gone.
+    on_cleanup(cx, move || {
+        if let Some(handle) = pending.get_value() {
+            timers.clear_timeout(handle);
+        }
+    });
+
+    debounced
+}
+
+/// Runs `action` once whatever `deps` reads has stopped changing for `ms`
+/// milliseconds.
+///
+/// Unlike [`debounce`], this doesn't make a new signal: it's for when the
+/// point of waiting is to do something, like saving, rather than to get a
+/// calmer value. `deps` should only read the signals to wait on (e.g.,
+/// `move || text.track()`), since it reruns on every change. `action`
+/// isn't run at the start, only after a change.
+pub fn use_debounced_effect(
+    cx: Scope,
+    deps: impl Fn() + 'static,
+    ms: i32,
+    action: impl FnMut() + 'static,
+) {
+    use_debounced_effect_with(cx, deps, ms, action, BrowserTimers);
+}
+
+fn use_debounced_effect_with(
+    cx: Scope,
+    deps: impl Fn() + 'static,
+    ms: i32,
+    action: impl FnMut() + 'static,
+    timers: impl Timers,
+) {
+    let action = Rc::new(RefCell::new(action));
+    let pending = store_value(cx, None);
+    let delay = Duration::from_millis(ms.max(0) as u64);
+    let cancel = move || {
+        if let Some(handle) = pending.get_value() {
+            timers.clear_timeout(handle);
+        }
+    };
+
+    create_effect(cx, move |prev: Option<()>| {
+        deps();
+        if prev.is_none() {
+            return;
+        }
+        // Each change starts the wait over.
+        cancel();
+        let action = Rc::clone(&action);
+        let handle = timers.set_timeout(move || (action.borrow_mut())(), delay);
+        pending.set_value(handle);
+    });
+
+    // Don't let `action` run after the component is gone.
+    on_cleanup(cx, cancel);
+}
+
+/// Returns a signal that follows `source`, but updates at most once every
+/// `ms` milliseconds.
+///
+/// The first change goes through straight away. Changes after that are held
+/// until the end of the window, and only the latest one is passed on.
+pub fn throttle<T: Clone + 'static>(cx: Scope, source: ReadSignal<T>, ms: i32) -> ReadSignal<T> {
+    throttle_with(cx, source, ms, BrowserTimers)
+}
+
+fn throttle_with<T: Clone + 'static, C: Timers>(
+    cx: Scope,
+    source: ReadSignal<T>,
+    ms: i32,
+    timers: C,
+) -> ReadSignal<T> {
+    let (throttled, set_throttled) = create_signal(cx, source.get_untracked());
+    let window = store_value(cx, None::<C::Timeout>);
+    let latest = store_value(cx, None::<T>);
+    let delay = Duration::from_millis(ms.max(0) as u64);
+
+    create_effect(cx, move |prev: Option<()>| {
+        let value = source.get();
+        if prev.is_none() {
+            return;
+        }
+        if window.with_value(Option::is_some) {
+            // We've updated recently, so save this for the end of the window.
+            // A newer value replaces any older one that's still waiting.
+            latest.set_value(Some(value));
+        } else {
+            set_throttled.set(value);
+            open_window(timers, window, latest, set_throttled, delay);
+        }
+    });
+
+    on_cleanup(cx, move || {
+        if let Some(handle) = window.get_value() {
+            timers.clear_timeout(handle);
+        }
+    });
+
+    throttled
+}
+
+/// Starts a window during which `throttle` holds back updates. When it ends,
+/// the latest held-back value (if any) is sent, which starts another window.
+fn open_window<T: Clone + 'static, C: Timers>(
+    timers: C,
+    window: StoredValue<Option<C::Timeout>>,
+    latest: StoredValue<Option<T>>,
+    set_throttled: WriteSignal<T>,
+    delay: Duration,
+) {
+    let handle = timers.set_timeout(
+        move || {
+            window.set_value(None);
+            if let Some(value) = latest.get_value() {
+                latest.set_value(None);
+                set_throttled.set(value);
+                open_window(timers, window, latest, set_throttled, delay);
+            }
+        },
+        delay,
+    );
+    window.set_value(handle);
+}
+
+/// Pauses and resumes an interval created by [`use_interval`].
+///
+/// This is `Copy`, so it can be used from as many event handlers as needed.
+#[derive(Clone, Copy)]
+pub struct IntervalControls {
+    running: RwSignal<bool>,
+}
+
+impl IntervalControls {
+    /// Stops calling the callback until [`resume`](Self::resume) is called.
+    pub fn pause(&self) {
+        self.running.set(false);
+    }
+
+    /// Starts calling the callback again, one full interval from now. Does
+    /// nothing if it's already running.
+    pub fn resume(&self) {
+        if !self.running.get_untracked() {
+            self.running.set(true);
+        }
+    }
+
+    /// Whether the callback is currently being called. This is reactive,
+    /// so it can be used to update the view.
+    pub fn is_running(&self) -> bool {
+        self.running.get()
+    }
+}
+
+/// Calls `f` every `ms` milliseconds until it's paused or `cx` is cleaned
+/// up. The first call is one full interval from now, not straight away.
+pub fn use_interval(cx: Scope, ms: u64, f: impl FnMut() + 'static) -> IntervalControls {
+    use_interval_with(cx, ms, f, BrowserTimers)
+}
+
+fn use_interval_with<C: Timers>(
+    cx: Scope,
+    ms: u64,
+    f: impl FnMut() + 'static,
+    timers: C,
+) -> IntervalControls {
+    let running = create_rw_signal(cx, true);
+    let interval = store_value(cx, None::<C::Interval>);
+    let clear = move || {
+        if let Some(handle) = interval.get_value() {
+            timers.clear_interval(handle);
+            interval.set_value(None);
+        }
+    };
+    // The browser needs an `Fn`, but `f` is `FnMut`, so it's called through
+    // a `RefCell` that each new interval shares.
+    let f = Rc::new(RefCell::new(f));
+
+    // This runs whenever `running` changes (and never on a server), and
+    // starts or stops the browser's interval to match.
+    create_effect(cx, move |_| {
+        clear();
+        if running.get() {
+            let f = Rc::clone(&f);
+            let handle = timers.set_interval(move || (f.borrow_mut())(), Duration::from_millis(ms));
+            interval.set_value(handle);
+        }
+    });
+
+    on_cleanup(cx, clear);
+
+    IntervalControls { running }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// A timeout or interval waiting in [`FakeTimers`].
+    struct FakeTimer {
+        id: usize,
+        due: u64,
+        /// How often an interval repeats. `None` for a timeout.
+        every: Option<u64>,
+        f: Rc<dyn Fn()>,
+    }
+
+    #[derive(Default)]
+    struct FakeTime {
+        now: u64,
+        next_id: usize,
+        timers: Vec<FakeTimer>,
+    }
+
+    thread_local! {
+        // Each test runs on its own thread, so each one starts at 0ms.
+        static FAKE_TIME: RefCell<FakeTime> = RefCell::default();
+    }
+
+    /// Timers that only fire when [`advance`] moves time past them.
+    #[derive(Clone, Copy)]
+    struct FakeTimers;
+
+    impl FakeTimers {
+        fn add(self, f: Rc<dyn Fn()>, delay: Duration, every: Option<u64>) -> Option<usize> {
+            FAKE_TIME.with(|time| {
+                let mut time = time.borrow_mut();
+                let id = time.next_id;
+                time.next_id += 1;
+                let due = time.now + delay.as_millis() as u64;
+                time.timers.push(FakeTimer { id, due, every, f });
+                Some(id)
+            })
+        }
+
+        fn remove(self, handle: usize) {
+            FAKE_TIME.with(|time| time.borrow_mut().timers.retain(|timer| timer.id != handle));
+        }
+    }
+
+    impl Timers for FakeTimers {
+        type Timeout = usize;
+        type Interval = usize;
+
+        fn set_timeout(self, f: impl FnOnce() + 'static, delay: Duration) -> Option<usize> {
+            let f = RefCell::new(Some(f));
+            let f = move || {
+                if let Some(f) = f.borrow_mut().take() {
+                    f();
+                }
+            };
+            self.add(Rc::new(f), delay, None)
+        }
+
+        fn clear_timeout(self, handle: usize) {
+            self.remove(handle);
+        }
+
+        fn set_interval(self, f: impl Fn() + 'static, delay: Duration) -> Option<usize> {
+            self.add(Rc::new(f), delay, Some(delay.as_millis() as u64))
+        }
+
+        fn clear_interval(self, handle: usize) {
+            self.remove(handle);
+        }
+    }
+
+    /// Moves time forward by `ms` milliseconds, firing every timer that
+    /// comes due on the way, in order.
+    fn advance(ms: u64) {
+        let end = FAKE_TIME.with(|time| time.borrow().now) + ms;
+        loop {
+            // The time is released before calling `f`, which may set or
+            // clear timers.
+            let next = FAKE_TIME.with(|time| {
+                let mut time = time.borrow_mut();
+                let index = (0..time.timers.len())
+                    .filter(|&index| time.timers[index].due <= end)
+                    .min_by_key(|&index| (time.timers[index].due, time.timers[index].id))?;
+                let mut timer = time.timers.remove(index);
+                time.now = timer.due;
+                let f = Rc::clone(&timer.f);
+                if let Some(every) = timer.every {
+                    timer.due += every.max(1);
+                    time.timers.push(timer);
+                }
+                Some(f)
+            });
+            match next {
+                Some(f) => f(),
+                None => break,
+            }
+        }
+        FAKE_TIME.with(|time| time.borrow_mut().now = end);
+    }
+
+    /// Runs `f` in a fresh reactive scope, then disposes of it.
+    fn with_scope(f: impl FnOnce(Scope) + 'static) {
+        let runtime = create_runtime();
+        create_scope(runtime, f).dispose();
+        runtime.dispose();
+    }
+
+    #[test]
+    fn zip_follows_both_signals() {
+        with_scope(|cx| {
+            let (a, set_a) = create_signal(cx, 1);
+            let (b, set_b) = create_signal(cx, "one");
+            let both = zip(cx, a.into(), b.into());
+            assert_eq!(both.get_untracked(), (1, "one"));
+
+            set_a.set(2);
+            assert_eq!(both.get_untracked(), (2, "one"));
+            set_b.set("two");
+            assert_eq!(both.get_untracked(), (2, "two"));
+        });
+    }
+
+    #[test]
+    fn map2_reruns_when_either_signal_changes() {
+        with_scope(|cx| {
+            let (a, set_a) = create_signal(cx, 1);
+            let (b, set_b) = create_signal(cx, 10);
+            let sum = map2(cx, a.into(), b.into(), |a, b| a + b);
+            let (seen, set_seen) = create_signal(cx, Vec::new());
+            create_effect(cx, move |_| {
+                let sum = sum.get();
+                set_seen.update(|seen| seen.push(sum));
+            });
+
+            set_a.set(2);
+            set_b.set(20);
+            assert_eq!(seen.get_untracked(), vec![11, 12, 22]);
+        });
+    }
+
+    #[test]
+    fn logged_memo_only_logs_changed_for_a_new_value() {
+        with_scope(|cx| {
+            let messages = Rc::new(RefCell::new(Vec::new()));
+            let (count, set_count) = create_signal(cx, 0);
+            let half = logged_memo_with(cx, "half", move || count.get() / 2, {
+                let messages = Rc::clone(&messages);
+                move |message| messages.borrow_mut().push(message)
+            });
+            half.get_untracked();
+
+            // 1 / 2 is still 0.
+            set_count.set(1);
+            half.get_untracked();
+            set_count.set(2);
+            half.get_untracked();
+            assert_eq!(
+                *messages.borrow(),
+                [
+                    "half recomputed",
+                    "half changed",
+                    "half recomputed",
+                    "half recomputed",
+                    "half changed",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn debounce_waits_for_changes_to_stop() {
+        with_scope(|cx| {
+            let (source, set_source) = create_signal(cx, 0);
+            let debounced = debounce_with(cx, source, 100, FakeTimers);
+
+            set_source.set(1);
+            advance(50);
+            assert_eq!(debounced.get_untracked(), 0);
+
+            // A change within the wait starts it over.
+            set_source.set(2);
+            advance(99);
+            assert_eq!(debounced.get_untracked(), 0);
+            advance(1);
+            assert_eq!(debounced.get_untracked(), 2);
+        });
+    }
+
+    #[test]
+    fn debounce_only_passes_on_the_last_value_of_a_burst() {
+        with_scope(|cx| {
+            let (source, set_source) = create_signal(cx, 0);
+            let debounced = debounce_with(cx, source, 100, FakeTimers);
+            let (updates, set_updates) = create_signal(cx, Vec::new());
+            create_effect(cx, move |_| {
+                let value = debounced.get();
+                set_updates.update(|updates| updates.push(value));
+            });
+
+            for value in 1..=5 {
+                set_source.set(value);
+                advance(10);
+            }
+            advance(100);
+            assert_eq!(updates.get_untracked(), vec![0, 5]);
+        });
+    }
+
+    #[test]
+    fn debounced_effect_runs_once_after_a_burst_of_changes() {
+        with_scope(|cx| {
+            let (text, set_text) = create_signal(cx, String::new());
+            let (saves, set_saves) = create_signal(cx, 0);
+            use_debounced_effect_with(
+                cx,
+                move || text.track(),
+                100,
+                move || set_saves.update(|saves| *saves += 1),
+                FakeTimers,
+            );
+
+            // Not at the start, only after a change.
+            advance(200);
+            assert_eq!(saves.get_untracked(), 0);
+
+            for c in ["a", "ab", "abc"] {
+                set_text.set(c.to_string());
+                advance(50);
+            }
+            assert_eq!(saves.get_untracked(), 0);
+            advance(50);
+            assert_eq!(saves.get_untracked(), 1);
+            advance(200);
+            assert_eq!(saves.get_untracked(), 1);
+        });
+    }
+
+    #[test]
+    fn debounced_effect_doesnt_run_after_cleanup() {
+        let saves = Rc::new(Cell::new(0));
+        let runtime = create_runtime();
+        create_scope(runtime, {
+            let saves = Rc::clone(&saves);
+            move |cx| {
+                let (text, set_text) = create_signal(cx, 0);
+                use_debounced_effect_with(
+                    cx,
+                    move || text.track(),
+                    100,
+                    move || saves.set(saves.get() + 1),
+                    FakeTimers,
+                );
+                set_text.set(1);
+            }
+        })
+        .dispose();
+        advance(100);
+        runtime.dispose();
+        assert_eq!(saves.get(), 0);
+    }
+
+    #[test]
+    fn throttle_passes_the_first_change_straight_through() {
+        with_scope(|cx| {
+            let (source, set_source) = create_signal(cx, 0);
+            let throttled = throttle_with(cx, source, 100, FakeTimers);
+
+            set_source.set(1);
+            assert_eq!(throttled.get_untracked(), 1);
+        });
+    }
+
+    #[test]
+    fn throttle_sends_the_latest_held_value_at_the_end_of_each_window() {
+        with_scope(|cx| {
+            let (source, set_source) = create_signal(cx, 0);
+            let throttled = throttle_with(cx, source, 100, FakeTimers);
+
+            set_source.set(1);
+            advance(10);
+            set_source.set(2);
+            set_source.set(3);
+            advance(89);
+            assert_eq!(throttled.get_untracked(), 1);
+            advance(1);
+            assert_eq!(throttled.get_untracked(), 3);
+
+            // Sending 3 started another window.
+            advance(50);
+            set_source.set(4);
+            assert_eq!(throttled.get_untracked(), 3);
+            advance(50);
+            assert_eq!(throttled.get_untracked(), 4);
+        });
+    }
+
+    #[test]
+    fn throttle_lets_a_change_through_once_a_window_ends_quietly() {
+        with_scope(|cx| {
+            let (source, set_source) = create_signal(cx, 0);
+            let throttled = throttle_with(cx, source, 100, FakeTimers);
+
+            set_source.set(1);
+            advance(100);
+            set_source.set(2);
+            assert_eq!(throttled.get_untracked(), 2);
+        });
+    }
+
+    #[test]
+    fn interval_first_fires_one_interval_from_now() {
+        with_scope(|cx| {
+            let (ticks, set_ticks) = create_signal(cx, 0);
+            use_interval_with(cx, 100, move || set_ticks.update(|n| *n += 1), FakeTimers);
+
+            assert_eq!(ticks.get_untracked(), 0);
+            advance(99);
+            assert_eq!(ticks.get_untracked(), 0);
+            advance(1);
+            assert_eq!(ticks.get_untracked(), 1);
+            advance(200);
+            assert_eq!(ticks.get_untracked(), 3);
+        });
+    }
+
+    #[test]
+    fn interval_pauses_and_resumes() {
+        with_scope(|cx| {
+            let (ticks, set_ticks) = create_signal(cx, 0);
+            let controls =
+                use_interval_with(cx, 100, move || set_ticks.update(|n| *n += 1), FakeTimers);
+
+            advance(250);
+            assert_eq!(ticks.get_untracked(), 2);
+
+            controls.pause();
+            assert!(!controls.running.get_untracked());
+            advance(1000);
+            assert_eq!(ticks.get_untracked(), 2);
+
+            // Resuming waits a full interval again, rather than picking up
+            // partway through the old one.
+            controls.resume();
+            advance(99);
+            assert_eq!(ticks.get_untracked(), 2);
+            advance(1);
+            assert_eq!(ticks.get_untracked(), 3);
+
+            // Resuming while running doesn't restart it.
+            advance(50);
+            controls.resume();
+            advance(50);
+            assert_eq!(ticks.get_untracked(), 4);
+        });
+    }
+}
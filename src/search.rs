@@ -0,0 +1,123 @@
+use leptos::*;
+use std::time::Duration;
+
+/// The made-up data set that [`search`] looks through.
+pub(crate) const FRUITS: &[&str] = &[
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Grapefruit",
+    "Lemon",
+    "Lime",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+    "Pineapple",
+    "Plum",
+    "Raspberry",
+    "Strawberry",
+];
+
+/// Waits for `duration` without blocking the browser.
+pub(crate) async fn sleep(duration: Duration) {
+    let (tx, rx) = futures::channel::oneshot::channel();
+    set_timeout(
+        move || {
+            _ = tx.send(());
+        },
+        duration,
+    );
+    _ = rx.await;
+}
+
+/// Pretends to ask a server for the fruits whose names contain `query`.
+///
+/// Searching for "error" pretends the server failed, so there's a way to
+/// see how failures are shown.
+async fn search(query: String) -> Result<Vec<String>, String> {
+    sleep(Duration::from_millis(500)).await;
+    answer(&query)
+}
+
+/// What the pretend server answers a [`search`] for `query` with, once
+/// it's done waiting.
+fn answer(query: &str) -> Result<Vec<String>, String> {
+    let query = query.to_lowercase();
+    if query == "error" {
+        return Err("The server couldn't be reached.".to_string());
+    }
+    Ok(FRUITS
+        .iter()
+        .filter(|fruit| fruit.to_lowercase().contains(&query))
+        .map(|fruit| fruit.to_string())
+        .collect())
+}
+
+/// Shows the results of searching for `query`, loading them again each
+/// time it changes.
+#[component]
+pub fn SearchBox(
+    cx: Scope,
+    /// What to search for. This should already be debounced, since every
+    /// change starts a new search.
+    query: ReadSignal<String>,
+) -> impl IntoView {
+    // A resource reruns its fetcher whenever its source signal changes. If
+    // an older search finishes after a newer one started, its results are
+    // thrown away, so only the latest query's results are ever shown.
+    let results = create_local_resource(cx, move || query.get(), search);
+
+    view! { cx,
+        <Suspense fallback=move || view! { cx, <p>"Loading…"</p> }>
+            {move || {
+                results.read(cx).map(|result| match result {
+                    // Refetching puts the resource back into loading, so
+                    // the `<Suspense>` shows its fallback instead of this
+                    // until the new search is done.
+                    Err(error) => view! { cx,
+                        <p class="red">"Search failed: " {error}</p>
+                        <button on:click=move |_| results.refetch()>"Retry"</button>
+                    }
+                    .into_view(cx),
+                    Ok(fruits) if fruits.is_empty() => {
+                        view! { cx, <p>"No results."</p> }.into_view(cx)
+                    }
+                    Ok(fruits) => {
+                        view! { cx,
+                            <ul>
+                                {fruits
+                                    .into_iter()
+                                    .map(|result| view! { cx, <li>{result}</li> })
+                                    .collect::<Vec<_>>()}
+                            </ul>
+                        }
+                        .into_view(cx)
+                    }
+                })
+            }}
+        </Suspense>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn searching_for_error_fails() {
+        assert!(answer("error").is_err());
+        assert!(answer("Error").is_err());
+    }
+
+    #[test]
+    fn search_finds_fruits_containing_the_query() {
+        assert_eq!(answer("berry").unwrap().len(), 4);
+        assert_eq!(answer("PEA").unwrap(), ["Peach", "Pear"]);
+        assert_eq!(answer("xyz"), Ok(Vec::new()));
+    }
+}
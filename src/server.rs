@@ -0,0 +1,132 @@
+//! A server that renders the demos to HTML, built with the `ssr` feature
+//! on top of `axum` and `leptos_axum`. Build the client that hydrates the HTML first, with
+//! `trunk build --no-default-features --features hydrate`, and then run
+//! `cargo run --no-default-features --features ssr` and open
+//! <http://127.0.0.1:3000>.
+//!
+//! Components are run on the server to render them, but effects and event
+//! handlers aren't, so only code that runs while a component is being
+//! created needs to avoid browser APIs. Currently, that's:
+//!
+//! - [`use_local_storage`](crate::storage::use_local_storage), which skips
+//!   `localStorage` and starts from its default value. The client starts
+//!   from the default too, and switches the counter, todos and theme to
+//!   their saved values once the page has hydrated.
+//! - [`provide_theme`](crate::theme::provide_theme), which skips checking
+//!   `prefers-color-scheme`.
+//! - [`SplitPane`](crate::split_pane::SplitPane), which skips listening
+//!   for the divider being dragged.
+//! - [`Tooltip`](crate::tooltip::Tooltip), which skips listening for
+//!   scrolling and Escape.
+//! - [`use_window_size`](crate::events::use_window_size), which skips
+//!   reading the window's size and listening for `resize`, and is always
+//!   `(0.0, 0.0)`.
+//!
+//! Everything else that touches the DOM, like reading the uncontrolled
+//! input's `NodeRef` in the forms demo, focusing the modal, or copying to
+//! the clipboard, already happens in an effect or event handler.
+
+use crate::{not_found::ResponseStatus, App};
+use axum::{
+    handler::HandlerWithoutStateExt,
+    http::{StatusCode, Uri},
+    response::Html,
+    routing::get,
+    Router,
+};
+use leptos::*;
+use leptos_axum::generate_route_list;
+use leptos_router::*;
+use std::{net::SocketAddr, path::Path};
+use tower_http::services::ServeDir;
+
+/// The address the server listens on.
+const ADDRESS: ([u8; 4], u16) = ([127, 0, 0, 1], 3000);
+
+/// Where `trunk build` puts `index.html` and the client's `.js` and `.wasm`.
+const DIST_DIR: &str = "dist";
+
+/// Handles requests until the process is stopped.
+#[tokio::main]
+pub async fn serve() {
+    // Every page the router knows about is rendered by `render`. Wildcard
+    // routes (like `NotFound`'s) are left out, because they'd also match
+    // the client's files. Those go to `ServeDir`, which falls back to
+    // `render` for anything that isn't a file, so the router still gets to
+    // show `NotFound` for them.
+    let routes = generate_route_list(|cx| view! { cx, <App/> }).await;
+    let app = routes
+        .iter()
+        .map(RouteListing::path)
+        .filter(|path| !path.contains('*'))
+        .fold(Router::new(), |app, path| app.route(path, get(render)))
+        .fallback_service(ServeDir::new(DIST_DIR).fallback(render.into_service()));
+
+    let address = SocketAddr::from(ADDRESS);
+    println!("Listening on http://{address}");
+    axum::Server::bind(&address)
+        .serve(app.into_make_service())
+        .await
+        .expect("to be able to listen on the address");
+}
+
+/// Renders the page for `uri` into the `index.html` built by `trunk`, which
+/// already loads the client.
+async fn render(uri: Uri) -> Result<(StatusCode, Html<String>), (StatusCode, String)> {
+    let shell = tokio::fs::read_to_string(Path::new(DIST_DIR).join("index.html"))
+        .await
+        .map_err(|err| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Couldn't read {DIST_DIR}/index.html. Has `trunk build` been run? {err}"),
+            )
+        })?;
+    let (status, app) = render_app(&uri.to_string());
+    let status = StatusCode::from_u16(status).unwrap_or(StatusCode::OK);
+    Ok((status, Html(insert_into_body(&shell, &app))))
+}
+
+/// Renders `App` at `url`. Also returns the HTTP status that the page asked
+/// for, e.g., 404 from `NotFound`.
+fn render_app(url: &str) -> (u16, String) {
+    // The router reads the current URL from this, instead of from the
+    // browser's location.
+    let integration = ServerIntegration {
+        path: format!("http://{}{url}", SocketAddr::from(ADDRESS)),
+    };
+    let status = ResponseStatus::new();
+    let app = leptos::ssr::render_to_string({
+        let status = status.clone();
+        move |cx| {
+            provide_context(cx, RouterIntegrationContext::new(integration));
+            provide_context(cx, status);
+            view! { cx, <App/> }
+        }
+    });
+    (status.get(), app)
+}
+
+/// Puts `html` at the start of the `<body>` of `shell`.
+fn insert_into_body(shell: &str, html: &str) -> String {
+    shell.replacen("<body>", &format!("<body>{html}"), 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_into_body_puts_the_app_before_the_shells_own_content() {
+        let shell = "<head></head><body><p>Loading</p></body>";
+        assert_eq!(
+            insert_into_body(shell, "<main></main>"),
+            "<head></head><body><main></main><p>Loading</p></body>"
+        );
+    }
+
+    #[test]
+    fn render_app_responds_with_the_status_the_page_asks_for() {
+        assert_eq!(render_app("/").0, 200);
+        assert_eq!(render_app("/no/such/page").0, 404);
+    }
+}
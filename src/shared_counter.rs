@@ -0,0 +1,40 @@
+use leptos::*;
+
+/// A count shared through context, so components anywhere below the one
+/// that provides it can use it without it being passed down as a prop.
+#[derive(Clone, Copy)]
+pub struct CounterContext(pub RwSignal<i32>);
+
+/// Provides a [`CounterContext`] to its children.
+#[component]
+pub fn CounterProvider(cx: Scope, children: Children) -> impl IntoView {
+    provide_context(cx, CounterContext(create_rw_signal(cx, 0)));
+    children(cx)
+}
+
+/// Shows the count from the nearest [`CounterProvider`].
+///
+/// `use_context` returns `None` when there's no provider above, which this
+/// handles by showing a message instead.
+#[component]
+pub fn CounterDisplay(cx: Scope) -> impl IntoView {
+    match use_context::<CounterContext>(cx) {
+        Some(CounterContext(count)) => view! { cx, <p>"Shared count: " {count}</p> }.into_view(cx),
+        None => view! { cx, <p class="red">"No `CounterProvider` above this display."</p> }
+            .into_view(cx),
+    }
+}
+
+/// Increments the count from the nearest [`CounterProvider`].
+///
+/// This has nothing sensible to do without a provider, so it uses
+/// `expect_context`, which panics with a message naming the missing type
+/// (in this case, `CounterContext`) if there isn't one.
+#[component]
+pub fn CounterButton(cx: Scope) -> impl IntoView {
+    let CounterContext(count) = expect_context::<CounterContext>(cx);
+
+    view! { cx,
+        <button on:click=move |_| count.update(|n| *n += 1)>"Increment shared count"</button>
+    }
+}
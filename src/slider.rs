@@ -0,0 +1,55 @@
+use leptos::*;
+
+/// A range input that's bound to `value` in both directions.
+#[component]
+pub fn Slider(
+    cx: Scope,
+    /// The current value. Values set from outside that are out of range
+    /// are shown clamped to `min..=max`, and the clamped value is saved
+    /// once the user next moves the slider.
+    value: RwSignal<i32>,
+    /// The lowest value the slider can be set to.
+    min: i32,
+    /// The highest value the slider can be set to.
+    max: i32,
+    #[prop(default = 1)]
+    /// How far apart the values the slider snaps to are.
+    step: i32,
+) -> impl IntoView {
+    let clamp = move |n: i32| n.clamp(min, max.max(min));
+    let clamped = move || clamp(value.get());
+    let on_input = move |ev| {
+        // The browser keeps the value in range, but the text could still
+        // fail to parse, in which case we leave `value` alone.
+        if let Ok(new_value) = event_target_value(&ev).parse::<i32>() {
+            value.set(clamp(new_value));
+        }
+    };
+
+    view! { cx,
+        <input
+            type="range"
+            min=min
+            max=max
+            step=step
+            prop:value=move || clamped().to_string()
+            on:input=on_input
+        />
+        <span>{clamped}</span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::render_html;
+
+    #[test]
+    fn slider_shows_an_out_of_range_value_clamped() {
+        let html = render_html(|cx| {
+            let value = create_rw_signal(cx, 150);
+            view! { cx, <Slider value=value min=0 max=100/> }
+        });
+        assert!(html.contains("<span>100</span>"), "{html}");
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{events::use_event_listener, storage::use_local_storage};
+use leptos::{ev::MouseEvent, html::Div, *};
+use wasm_bindgen::JsCast;
+
+/// The narrowest either side can be made, as a fraction of the whole width.
+const MIN_RATIO: f64 = 0.1;
+
+/// Moves `ratio` into the range the divider can be dragged to.
+fn clamp_ratio(ratio: f64) -> f64 {
+    if ratio.is_nan() {
+        0.5
+    } else {
+        ratio.clamp(MIN_RATIO, 1.0 - MIN_RATIO)
+    }
+}
+
+/// The content of a [SplitPane]'s left pane.
+#[slot]
+pub struct LeftPane {
+    children: Children,
+}
+
+/// The content of a [SplitPane]'s right pane.
+#[slot]
+pub struct RightPane {
+    children: Children,
+}
+
+/// Two panes side by side, with a divider between them that can be dragged
+/// to change how much of the width each one gets.
+#[component]
+pub fn SplitPane(
+    cx: Scope,
+    /// The left pane, passed as `<LeftPane slot:left>`.
+    left: LeftPane,
+    /// The right pane, passed as `<RightPane slot:right>`.
+    right: RightPane,
+    #[prop(optional)]
+    /// If set, the position of the divider is saved to `localStorage` under
+    /// this key.
+    storage_key: Option<&'static str>,
+) -> impl IntoView {
+    // How much of the width the left pane gets, from 0 to 1.
+    let (ratio, set_ratio) = match storage_key {
+        Some(key) => {
+            let (ratio, set_ratio) = use_local_storage(cx, key, 0.5);
+            // A saved ratio could have been edited by hand, or saved before
+            // `MIN_RATIO` changed, and leave a pane too narrow to grab.
+            let saved = ratio.get_untracked();
+            if clamp_ratio(saved) != saved {
+                set_ratio.set(clamp_ratio(saved));
+            }
+            (ratio, set_ratio)
+        }
+        None => {
+            let (ratio, set_ratio) = create_signal(cx, 0.5);
+            (ratio.into(), set_ratio)
+        }
+    };
+    let (dragging, set_dragging) = create_signal(cx, false);
+    let container: NodeRef<Div> = create_node_ref(cx);
+
+    let on_mousedown = move |ev: MouseEvent| {
+        // Otherwise the drag would select the text in the panes.
+        ev.prevent_default();
+        set_dragging.set(true);
+    };
+
+    // The pointer soon leaves the divider while dragging it, so the moves
+    // and the release are listened for on the whole window.
+    if !cfg!(feature = "ssr") {
+        use_event_listener(cx, window().into(), "mousemove", move |ev| {
+            if !dragging.get_untracked() {
+                return;
+            }
+            let (Some(ev), Some(container)) = (
+                ev.dyn_ref::<web_sys::MouseEvent>(),
+                container.get_untracked(),
+            ) else {
+                return;
+            };
+            let rect = container.get_bounding_client_rect();
+            if rect.width() > 0.0 {
+                let ratio = (f64::from(ev.client_x()) - rect.left()) / rect.width();
+                set_ratio.set(clamp_ratio(ratio));
+            }
+        });
+        use_event_listener(cx, window().into(), "mouseup", move |_| {
+            set_dragging.set(false)
+        });
+    }
+
+    view! { cx,
+        <div class="split-pane" class:dragging=move || dragging.get() node_ref=container>
+            <div style=move || format!("flex-basis: {}%", ratio.get() * 100.0)>
+                {(left.children)(cx)}
+            </div>
+            <div
+                class="split-pane-divider"
+                role="separator"
+                aria-orientation="vertical"
+                aria-valuenow=move || (ratio.get() * 100.0).round()
+                on:mousedown=on_mousedown
+            />
+            <div class="split-pane-right">{(right.children)(cx)}</div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_ratio_keeps_both_panes_wide_enough() {
+        assert_eq!(clamp_ratio(0.3), 0.3);
+        assert_eq!(clamp_ratio(0.0), MIN_RATIO);
+        assert_eq!(clamp_ratio(-2.0), MIN_RATIO);
+        assert_eq!(clamp_ratio(1.0), 1.0 - MIN_RATIO);
+        assert_eq!(clamp_ratio(f64::NAN), 0.5);
+    }
+}
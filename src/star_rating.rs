@@ -0,0 +1,67 @@
+use leptos::{ev::KeyboardEvent, *};
+
+/// A row of stars for picking a rating from 0 to `max`.
+///
+/// Hovering over a star previews that rating, and clicking the star that's
+/// already selected clears the rating back to 0.
+#[component]
+pub fn StarRating(
+    cx: Scope,
+    /// The current rating, which is updated when the user picks one.
+    value: RwSignal<u8>,
+    #[prop(default = 5)]
+    /// How many stars to show.
+    max: u8,
+) -> impl IntoView {
+    let (hovered, set_hovered) = create_signal(cx, None::<u8>);
+    // While hovering, show the rating the user would pick by clicking.
+    let shown = move || hovered.get().unwrap_or_else(|| value.get());
+
+    let select = move |n: u8| value.update(|value| *value = if *value == n { 0 } else { n });
+    let on_keydown = move |ev: KeyboardEvent| {
+        let current = value.get_untracked();
+        let next = match ev.key().as_str() {
+            "ArrowRight" | "ArrowUp" => current.saturating_add(1).min(max),
+            "ArrowLeft" | "ArrowDown" => current.saturating_sub(1),
+            "Home" => 0,
+            "End" => max,
+            _ => return,
+        };
+        ev.prevent_default();
+        value.set(next);
+    };
+
+    let stars = (1..=max)
+        .map(|n| {
+            view! { cx,
+                <button
+                    type="button"
+                    class="star"
+                    class:filled=move || n <= shown()
+                    // The container handles the keyboard, so the stars
+                    // don't need to be in the tab order.
+                    tabindex="-1"
+                    aria-label=format!("{n} stars")
+                    on:click=move |_| select(n)
+                    on:mouseenter=move |_| set_hovered.set(Some(n))
+                >
+                    {move || if n <= shown() { "★" } else { "☆" }}
+                </button>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! { cx,
+        <div
+            role="slider"
+            tabindex="0"
+            aria-valuemin="0"
+            aria-valuemax=max.to_string()
+            aria-valuenow=move || value.get().to_string()
+            on:keydown=on_keydown
+            on:mouseleave=move |_| set_hovered.set(None)
+        >
+            {stars}
+        </div>
+    }
+}
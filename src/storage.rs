@@ -0,0 +1,130 @@
+use leptos::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Creates a signal that's saved to `localStorage` under `key` as JSON.
+///
+/// The signal starts with the saved value, and is saved again every time it
+/// changes. If there's nothing saved yet, the saved JSON can't be parsed as
+/// a `T`, or `localStorage` isn't available (e.g., because it's disabled,
+/// or we're rendering on a server), the signal starts from `default`
+/// instead.
+///
+/// With the `hydrate` feature, the signal starts from `default` like it
+/// did on the server, so the page matches the HTML it's taking over, and
+/// only switches to the saved value once the page has hydrated.
+pub fn use_local_storage<T>(cx: Scope, key: &str, default: T) -> (Signal<T>, WriteSignal<T>)
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    // There's no `window()` on the server, and calling it would panic.
+    let storage = if cfg!(feature = "ssr") {
+        None
+    } else {
+        window().local_storage().ok().flatten()
+    };
+    let saved = storage
+        .as_ref()
+        .and_then(|storage| storage.get_item(key).ok().flatten());
+    let hydrating = cfg!(feature = "hydrate");
+    let initial = if hydrating {
+        default
+    } else {
+        saved_or(saved.as_deref(), default)
+    };
+
+    let (value, set_value) = create_signal(cx, initial);
+
+    if hydrating {
+        // Effects run as soon as they're created, which is still partway
+        // through hydrating, so this waits for a microtask, by which time
+        // the rest of the page has been hydrated too.
+        create_effect(cx, move |_| {
+            let saved = saved
+                .as_deref()
+                .and_then(|json| serde_json::from_str::<T>(json).ok());
+            if let Some(saved) = saved {
+                queue_microtask(move || set_value.set(saved));
+            }
+        });
+    }
+
+    let key = key.to_string();
+    create_effect(cx, move |prev: Option<()>| {
+        value.with(|value| {
+            // The first run would only save the value we just loaded (or,
+            // while hydrating, overwrite the saved one with `default`).
+            if prev.is_none() {
+                return;
+            }
+            if let (Some(storage), Ok(json)) = (&storage, serde_json::to_string(value)) {
+                // There's nothing useful we can do if this fails (e.g., the
+                // quota is exceeded), so the value just won't be persisted.
+                _ = storage.set_item(&key, &json);
+            }
+        });
+    });
+
+    (value.into(), set_value)
+}
+
+/// Parses `saved` as a `T`, or returns `default` if nothing was saved or it
+/// isn't a valid `T`.
+fn saved_or<T: DeserializeOwned>(saved: Option<&str>, default: T) -> T {
+    saved
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_or_parses_saved_json() {
+        assert_eq!(saved_or(Some("42"), 0), 42);
+        assert_eq!(
+            saved_or(Some(r#"["a","b"]"#), Vec::<String>::new()),
+            vec!["a", "b"]
+        );
+    }
+
+    #[test]
+    fn saved_or_falls_back_to_the_default() {
+        assert_eq!(saved_or(None, 7), 7);
+        assert_eq!(saved_or(Some("not json"), 7), 7);
+        // Valid JSON, but not an `i32`.
+        assert_eq!(saved_or(Some(r#""seven""#), 7), 7);
+        assert_eq!(saved_or(Some("-1"), 7_u32), 7);
+    }
+}
+
+// These need a browser, so they're run with `wasm-pack test --headless
+// --firefox` (or `--chrome`) rather than `cargo test`.
+#[cfg(all(test, target_arch = "wasm32"))]
+mod browser_tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn use_local_storage_loads_and_saves_the_value() {
+        let storage = window().local_storage().unwrap().unwrap();
+        storage.set_item("storage-test", "5").unwrap();
+
+        let runtime = create_runtime();
+        create_scope(runtime, |cx| {
+            let (value, set_value) = use_local_storage(cx, "storage-test", 0);
+            assert_eq!(value.get_untracked(), 5);
+            set_value.set(6);
+        })
+        .dispose();
+        runtime.dispose();
+
+        assert_eq!(
+            storage.get_item("storage-test").unwrap().as_deref(),
+            Some("6")
+        );
+        storage.remove_item("storage-test").unwrap();
+    }
+}
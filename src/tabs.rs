@@ -0,0 +1,106 @@
+use leptos::{ev::KeyboardEvent, html::Div, *};
+use wasm_bindgen::JsCast;
+
+/// Shared between [`Tabs`] and each [`Tab`] inside it, so that the tabs
+/// can tell `Tabs` their titles without it having to inspect its children.
+#[derive(Clone, Copy)]
+struct TabsContext {
+    titles: RwSignal<Vec<String>>,
+    active: RwSignal<usize>,
+}
+
+/// Shows a header for each [`Tab`] inside it, and the content of whichever
+/// tab is selected.
+#[component]
+pub fn Tabs(cx: Scope, children: Children) -> impl IntoView {
+    let titles = create_rw_signal(cx, Vec::new());
+    let active = create_rw_signal(cx, 0);
+    provide_context(cx, TabsContext { titles, active });
+
+    // Each `Tab` registers itself when it's created, so this has to run
+    // before we render the headers.
+    let children = children(cx);
+
+    let tab_list: NodeRef<Div> = create_node_ref(cx);
+    let select = move |index: usize| {
+        active.set(index);
+        // Keep focus on the selected header so the arrow keys keep working.
+        if let Some(header) = tab_list
+            .get()
+            .and_then(|list| list.children().item(index as u32))
+            .and_then(|header| header.dyn_into::<web_sys::HtmlElement>().ok())
+        {
+            _ = header.focus();
+        }
+    };
+    let on_keydown = move |ev: KeyboardEvent| {
+        let count = titles.with(Vec::len);
+        if count == 0 {
+            return;
+        }
+        let current = active.get_untracked();
+        match ev.key().as_str() {
+            "ArrowRight" => select((current + 1) % count),
+            "ArrowLeft" => select((current + count - 1) % count),
+            "Home" => select(0),
+            "End" => select(count - 1),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    let headers = move || {
+        titles
+            .get()
+            .into_iter()
+            .enumerate()
+            .map(|(index, title)| {
+                let is_active = move || active.get() == index;
+                view! { cx,
+                    <button
+                        role="tab"
+                        aria-selected=move || is_active().to_string()
+                        // Only the selected tab is in the tab order; the
+                        // others are reached with the arrow keys.
+                        tabindex=move || if is_active() { "0" } else { "-1" }
+                        on:click=move |_| select(index)
+                    >
+                        {title}
+                    </button>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! { cx,
+        <div role="tablist" node_ref=tab_list on:keydown=on_keydown>
+            {headers}
+        </div>
+        {children}
+    }
+}
+
+/// A single tab in [`Tabs`]. Its children are only shown while it's
+/// selected.
+#[component]
+pub fn Tab(
+    cx: Scope,
+    /// The text shown in this tab's header.
+    #[prop(into)]
+    title: String,
+    children: Children,
+) -> impl IntoView {
+    let TabsContext { titles, active } = use_context(cx).expect("`Tab` to be used inside `Tabs`");
+    let index = titles
+        .try_update(|titles| {
+            titles.push(title);
+            titles.len() - 1
+        })
+        .unwrap_or_default();
+
+    view! { cx,
+        <div role="tabpanel" class:hidden=move || active.get() != index>
+            {children(cx)}
+        </div>
+    }
+}
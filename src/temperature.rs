@@ -0,0 +1,94 @@
+use crate::reactive_utils::map2;
+use leptos::*;
+
+/// Formats a temperature to at most one decimal place, without a trailing
+/// `.0`, so that `21.0` shows as `21` and `21.14` as `21.1`.
+fn format_temperature(degrees: f64) -> String {
+    let rounded = (degrees * 10.0).round() / 10.0;
+    // Avoid showing `-0` for values that round to zero from below.
+    if rounded == 0.0 {
+        "0".to_string()
+    } else {
+        rounded.to_string()
+    }
+}
+
+/// Two inputs, in Celsius and Fahrenheit, that each update the other.
+///
+/// Rather than deriving both fields from a single temperature, each keeps
+/// the text it was given and only the *other* field is recomputed. That
+/// way there's no loop between the two, the field being typed in is never
+/// rewritten under the cursor, and converting back and forth can't drift
+/// through repeated rounding. Text that isn't a number leaves the other
+/// field as it was.
+#[component]
+pub fn TempConverter(cx: Scope) -> impl IntoView {
+    let (celsius, set_celsius) = create_signal(cx, "0".to_string());
+    let (fahrenheit, set_fahrenheit) = create_signal(cx, "32".to_string());
+
+    let on_celsius = move |ev| {
+        let text = event_target_value(&ev);
+        if let Ok(degrees) = text.trim().parse::<f64>() {
+            set_fahrenheit.set(format_temperature(degrees * 9.0 / 5.0 + 32.0));
+        }
+        set_celsius.set(text);
+    };
+    let on_fahrenheit = move |ev| {
+        let text = event_target_value(&ev);
+        if let Ok(degrees) = text.trim().parse::<f64>() {
+            set_celsius.set(format_temperature((degrees - 32.0) * 5.0 / 9.0));
+        }
+        set_fahrenheit.set(text);
+    };
+    let summary = map2(
+        cx,
+        celsius.into(),
+        fahrenheit.into(),
+        |celsius, fahrenheit| format!("{celsius} °C is {fahrenheit} °F"),
+    );
+
+    view! { cx,
+        <label>
+            <input type="text" on:input=on_celsius prop:value=move || celsius.get()/>
+            " °C"
+        </label>
+        " = "
+        <label>
+            <input type="text" on:input=on_fahrenheit prop:value=move || fahrenheit.get()/>
+            " °F"
+        </label>
+        <p>{summary}</p>
+    }
+}
+
+#[component]
+pub fn AppSeven(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <h1>"Temperature"</h1>
+        <TempConverter/>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_temperature_drops_a_trailing_zero() {
+        assert_eq!(format_temperature(21.0), "21");
+        assert_eq!(format_temperature(-40.0), "-40");
+    }
+
+    #[test]
+    fn format_temperature_rounds_to_one_decimal_place() {
+        assert_eq!(format_temperature(21.14), "21.1");
+        assert_eq!(format_temperature(21.15), "21.2");
+        assert_eq!(format_temperature(-17.7777), "-17.8");
+    }
+
+    #[test]
+    fn format_temperature_never_shows_negative_zero() {
+        assert_eq!(format_temperature(-0.0), "0");
+        assert_eq!(format_temperature(-0.04), "0");
+    }
+}
@@ -0,0 +1,84 @@
+//! Helpers shared by the tests in each module.
+
+use leptos::*;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::JsCast;
+
+/// Renders `f` to HTML, without the comments and IDs that are only there for
+/// hydration, so tests can look for the markup they care about.
+pub fn render_html<F, N>(f: F) -> String
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    let mut html = leptos::ssr::render_to_string(f);
+    while let Some(start) = html.find("<!--") {
+        let end = html[start..]
+            .find("-->")
+            .map_or(html.len(), |end| start + end + 3);
+        html.replace_range(start..end, "");
+    }
+    while let Some(start) = html.find(" id=\"_") {
+        let value = start + " id=\"".len();
+        let end = html[value..]
+            .find('"')
+            .map_or(html.len(), |end| value + end + 1);
+        html.replace_range(start..end, "");
+    }
+    html
+}
+
+/// Mounts `f` into a new `<div>` at the end of the page, and returns the
+/// `<div>`.
+///
+/// Leptos handles most events with a single listener on the window, so the
+/// `<div>` has to be part of the page for events like clicks to reach it.
+#[cfg(target_arch = "wasm32")]
+pub fn mount<F, N>(f: F) -> web_sys::HtmlElement
+where
+    F: FnOnce(Scope) -> N + 'static,
+    N: IntoView,
+{
+    let root = document()
+        .create_element("div")
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>();
+    document().body().unwrap().append_child(&root).unwrap();
+    mount_to(root.clone(), f);
+    root
+}
+
+/// Every `<button>` inside `root` whose text is `text`, in document order.
+#[cfg(target_arch = "wasm32")]
+pub fn buttons(root: &web_sys::HtmlElement, text: &str) -> Vec<web_sys::HtmlElement> {
+    let buttons = root.query_selector_all("button").unwrap();
+    (0..buttons.length())
+        .filter_map(|index| buttons.item(index))
+        .filter(|button| {
+            button
+                .text_content()
+                .is_some_and(|content| content.trim() == text)
+        })
+        .map(JsCast::unchecked_into)
+        .collect()
+}
+
+/// The first `<button>` inside `root` whose text is `text`.
+#[cfg(target_arch = "wasm32")]
+pub fn button(root: &web_sys::HtmlElement, text: &str) -> web_sys::HtmlElement {
+    buttons(root, text)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("no {text:?} button"))
+}
+
+/// Replaces the text in `input` as if it had been typed, firing `input` the
+/// way the browser does.
+#[cfg(target_arch = "wasm32")]
+pub fn type_into(input: &web_sys::HtmlInputElement, text: &str) {
+    input.set_value(text);
+    let mut init = web_sys::EventInit::new();
+    init.bubbles(true);
+    let event = web_sys::Event::new_with_event_init_dict("input", &init).unwrap();
+    input.dispatch_event(&event).unwrap();
+}
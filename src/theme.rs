@@ -0,0 +1,86 @@
+use crate::storage::use_local_storage;
+use leptos::*;
+
+/// The `localStorage` key the theme is saved under.
+const STORAGE_KEY: &str = "theme";
+
+/// Whether the site is in dark mode, shared with every component through
+/// context rather than being passed down as props.
+#[derive(Clone, Copy)]
+pub struct ThemeContext {
+    pub dark: Signal<bool>,
+    pub set_dark: WriteSignal<bool>,
+}
+
+/// Creates the theme signal and provides it to every component below `cx`.
+///
+/// The initial theme comes from `localStorage` if it was saved before, and
+/// otherwise from the browser's `prefers-color-scheme` setting. When
+/// rendering on a server, where neither is available, it's light.
+pub fn provide_theme(cx: Scope) -> ThemeContext {
+    if !cfg!(feature = "ssr") {
+        upgrade_saved_theme();
+    }
+
+    let prefers_dark = !cfg!(feature = "ssr")
+        && window()
+            .match_media("(prefers-color-scheme: dark)")
+            .ok()
+            .flatten()
+            .is_some_and(|query| query.matches());
+
+    let (dark, set_dark) = use_local_storage(cx, STORAGE_KEY, prefers_dark);
+
+    let theme = ThemeContext { dark, set_dark };
+    provide_context(cx, theme);
+    theme
+}
+
+/// Rewrites a theme saved as `"dark"` or `"light"`, as it was before it
+/// was saved as JSON, so that `use_local_storage` can parse it instead of
+/// resetting it.
+fn upgrade_saved_theme() {
+    let Some(storage) = window().local_storage().ok().flatten() else {
+        return;
+    };
+    let saved = storage.get_item(STORAGE_KEY).ok().flatten();
+    if let Some(json) = saved.as_deref().and_then(upgraded_theme) {
+        _ = storage.set_item(STORAGE_KEY, json);
+    }
+}
+
+/// The JSON for a theme saved in the old format, or `None` if `saved` isn't
+/// in the old format.
+fn upgraded_theme(saved: &str) -> Option<&'static str> {
+    match saved {
+        "dark" => Some("true"),
+        "light" => Some("false"),
+        _ => None,
+    }
+}
+
+/// A button that switches between light and dark mode.
+#[component]
+pub fn ThemeToggle(cx: Scope) -> impl IntoView {
+    let ThemeContext { dark, set_dark } =
+        use_context(cx).expect("`ThemeToggle` to be used inside `provide_theme`");
+
+    view! { cx,
+        <button on:click=move |_| set_dark.update(|dark| *dark = !*dark)>
+            {move || if dark.get() { "Light mode" } else { "Dark mode" }}
+        </button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upgraded_theme_converts_only_the_old_format() {
+        assert_eq!(upgraded_theme("dark"), Some("true"));
+        assert_eq!(upgraded_theme("light"), Some("false"));
+        assert_eq!(upgraded_theme("true"), None);
+        assert_eq!(upgraded_theme("\"dark\""), None);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{
+    callback::Callback,
+    progress::ProgressBar,
+    reactive_utils::use_interval,
+    tabs::{Tab, Tabs},
+};
+use leptos::*;
+use std::time::Duration;
+
+/// How often the stopwatch updates.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Formats a number of milliseconds as `MM:SS.t`.
+fn format_elapsed(ms: u64) -> String {
+    let minutes = ms / 60_000;
+    let seconds = (ms / 1000) % 60;
+    let tenths = (ms / 100) % 10;
+    format!("{minutes:02}:{seconds:02}.{tenths}")
+}
+
+/// Returns the current local time, formatted as `HH:MM:SS`.
+fn current_time() -> String {
+    let now = js_sys::Date::new_0();
+    format!(
+        "{:02}:{:02}:{:02}",
+        now.get_hours(),
+        now.get_minutes(),
+        now.get_seconds()
+    )
+}
+
+/// Asks the browser to call `tick` before its next repaint, and keeps asking
+/// after each call, storing the latest request in `frame` so it can be
+/// cancelled.
+fn schedule_frame(
+    frame: StoredValue<Option<AnimationFrameRequestHandle>>,
+    tick: impl Fn() + Copy + 'static,
+) {
+    let handle = request_animation_frame_with_handle(move || {
+        tick();
+        schedule_frame(frame, tick);
+    })
+    .ok();
+    frame.set_value(handle);
+}
+
+/// Shows the current time, updated every second.
+///
+/// This uses `request_animation_frame` rather than an interval. The browser
+/// calls it before each repaint (usually 60 times a second), and pauses it
+/// entirely while the tab is in the background. Most frames the time text
+/// is still the same, so we only update the signal when the second changes.
+#[component]
+pub fn Clock(cx: Scope) -> impl IntoView {
+    let (time, set_time) = create_signal(cx, String::new());
+    let frame = store_value(cx, None::<AnimationFrameRequestHandle>);
+
+    // Each frame schedules the next, so without cancelling the pending one
+    // here, frames would keep being requested forever.
+    on_cleanup(cx, move || {
+        if let Some(handle) = frame.get_value() {
+            handle.cancel();
+        }
+    });
+    // Starting from an effect means this only happens in the browser.
+    create_effect(cx, move |_| {
+        let tick = move || {
+            let now = current_time();
+            if time.with_untracked(|time| *time != now) {
+                set_time.set(now);
+            }
+        };
+        tick();
+        schedule_frame(frame, tick);
+    });
+
+    view! { cx,
+        <p><code>{time}</code></p>
+    }
+}
+
+/// A stopwatch that can be started, stopped and reset.
+#[component]
+pub fn Stopwatch(cx: Scope) -> impl IntoView {
+    let (elapsed, set_elapsed) = create_signal(cx, 0u64);
+    let ticker = use_interval(cx, TICK.as_millis() as u64, move || {
+        set_elapsed.update(|ms| *ms += TICK.as_millis() as u64)
+    });
+    // The stopwatch waits for "Start" to be clicked.
+    ticker.pause();
+
+    let reset = move |_| {
+        ticker.pause();
+        set_elapsed.set(0);
+    };
+
+    view! { cx,
+        <p><code>{move || format_elapsed(elapsed.get())}</code></p>
+        <button on:click=move |_| ticker.resume() disabled=move || ticker.is_running()>
+            "Start"
+        </button>
+        <button on:click=move |_| ticker.pause() disabled=move || !ticker.is_running()>
+            "Stop"
+        </button>
+        <button on:click=reset>"Reset"</button>
+    }
+}
+
+/// Counts down from `seconds` to zero once started, showing the remaining
+/// time as a progress bar.
+#[component]
+pub fn Countdown(
+    cx: Scope,
+    /// How many seconds to count down from.
+    seconds: u32,
+    #[prop(optional, into)]
+    /// Called when the countdown reaches zero.
+    on_finish: Option<Callback<()>>,
+) -> impl IntoView {
+    let (remaining, set_remaining) = create_signal(cx, seconds);
+    let interval = store_value(cx, None::<IntervalHandle>);
+
+    let stop = move || {
+        if let Some(handle) = interval.get_value() {
+            handle.clear();
+            interval.set_value(None);
+        }
+    };
+    // Starting always begins again from `seconds`, so a finished countdown
+    // can be run (and will call `on_finish`) again.
+    let start = move |_| {
+        stop();
+        set_remaining.set(seconds);
+        let on_finish = on_finish.clone();
+        let handle = set_interval_with_handle(
+            move || {
+                set_remaining.update(|remaining| *remaining = remaining.saturating_sub(1));
+                if remaining.get_untracked() == 0 {
+                    stop();
+                    if let Some(on_finish) = &on_finish {
+                        on_finish.call(());
+                    }
+                }
+            },
+            Duration::from_secs(1),
+        )
+        .ok();
+        interval.set_value(handle);
+    };
+
+    on_cleanup(cx, stop);
+
+    let max = u16::try_from(seconds).unwrap_or(u16::MAX);
+    let progress = Signal::derive(cx, move || remaining.get() as i32);
+
+    view! { cx,
+        <p>{remaining} "s"</p>
+        <ProgressBar max=max progress=progress/>
+        <button on:click=start>"Start"</button>
+    }
+}
+
+#[component]
+pub fn AppFive(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <h1>"Timers"</h1>
+        <Tabs>
+            <Tab title="Stopwatch">
+                <Stopwatch/>
+            </Tab>
+            <Tab title="Clock">
+                <Clock/>
+            </Tab>
+            <Tab title="Countdown">
+                <Countdown seconds=10 on_finish=|_| log!("Countdown finished!")/>
+            </Tab>
+        </Tabs>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_elapsed_shows_minutes_seconds_and_tenths() {
+        assert_eq!(format_elapsed(0), "00:00.0");
+        assert_eq!(format_elapsed(1_234), "00:01.2");
+        assert_eq!(format_elapsed(59_999), "00:59.9");
+        assert_eq!(format_elapsed(60_000), "01:00.0");
+        assert_eq!(format_elapsed(754_300), "12:34.3");
+    }
+
+    #[test]
+    fn format_elapsed_keeps_counting_minutes_past_an_hour() {
+        assert_eq!(format_elapsed(3_600_000), "60:00.0");
+    }
+}
@@ -0,0 +1,123 @@
+use leptos::*;
+use std::time::Duration;
+
+/// What kind of message a [`Toast`] is, which decides how it's styled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastKind {
+    #[default]
+    Info,
+    Success,
+    Error,
+}
+
+impl ToastKind {
+    fn class(self) -> &'static str {
+        match self {
+            ToastKind::Info => "toast-info",
+            ToastKind::Success => "toast-success",
+            ToastKind::Error => "toast-error",
+        }
+    }
+}
+
+/// A single notification shown by [`ToastHost`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Toast {
+    pub id: usize,
+    pub text: String,
+    pub kind: ToastKind,
+}
+
+/// Lets any component show a notification.
+///
+/// ```ignore
+/// let toasts = expect_context::<ToastContext>(cx);
+/// toasts.push("Saved!");
+/// ```
+#[derive(Clone, Copy)]
+pub struct ToastContext {
+    toasts: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<usize>,
+}
+
+impl ToastContext {
+    /// Shows an informational toast.
+    pub fn push(&self, text: impl Into<String>) {
+        self.push_kind(text, ToastKind::Info);
+    }
+
+    /// Shows a toast of the given kind.
+    pub fn push_kind(&self, text: impl Into<String>, kind: ToastKind) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        let toast = Toast {
+            id,
+            text: text.into(),
+            kind,
+        };
+        self.toasts.update(|toasts| toasts.push(toast));
+    }
+
+    /// Removes a toast before it would have been dismissed automatically.
+    pub fn dismiss(&self, id: usize) {
+        self.toasts
+            .update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+}
+
+/// Creates the list of toasts and provides it to every component below
+/// `cx`. A [`ToastHost`] should be rendered somewhere below it too.
+pub fn provide_toasts(cx: Scope) -> ToastContext {
+    let toasts = ToastContext {
+        toasts: create_rw_signal(cx, Vec::new()),
+        next_id: store_value(cx, 0),
+    };
+    provide_context(cx, toasts);
+    toasts
+}
+
+/// Shows the toasts that have been pushed to the [`ToastContext`], each of
+/// which disappears after a while or when clicked.
+#[component]
+pub fn ToastHost(
+    cx: Scope,
+    #[prop(default = 3000)]
+    /// How many milliseconds each toast is shown for.
+    duration_ms: u64,
+) -> impl IntoView {
+    let context = expect_context::<ToastContext>(cx);
+
+    view! { cx,
+        <div class="toast-host" aria-live="polite">
+            <For
+                each=move || context.toasts.get()
+                key=|toast| toast.id
+                view=move |cx, toast: Toast| {
+                    let id = toast.id;
+                    // Each row has its own scope, which is disposed of when
+                    // the toast is removed. Clearing the timeout there means
+                    // it can't fire for a toast that's already gone.
+                    let timeout = set_timeout_with_handle(
+                        move || context.dismiss(id),
+                        Duration::from_millis(duration_ms),
+                    )
+                    .ok();
+                    on_cleanup(cx, move || {
+                        if let Some(timeout) = timeout {
+                            timeout.clear();
+                        }
+                    });
+
+                    view! { cx,
+                        <div
+                            class=format!("toast {}", toast.kind.class())
+                            on:click=move |_| context.dismiss(id)
+                        >
+                            {toast.text}
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{
+    search::sleep,
+    storage::use_local_storage,
+    toast::{ToastContext, ToastKind},
+};
+use leptos::{ev::KeyboardEvent, *};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// The `localStorage` key the todos are saved under.
+const STORAGE_KEY: &str = "todos";
+
+/// The `localStorage` key the selected filter is saved under.
+const FILTER_STORAGE_KEY: &str = "todos-filter";
+
+/// Which todos a [`TodoList`] shows.
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Filter {
+    All,
+    Active,
+    Completed,
+}
+
+impl Filter {
+    /// Every filter, in the order their buttons are shown.
+    const ALL: [Filter; 3] = [Filter::All, Filter::Active, Filter::Completed];
+
+    fn label(self) -> &'static str {
+        match self {
+            Filter::All => "All",
+            Filter::Active => "Active",
+            Filter::Completed => "Completed",
+        }
+    }
+
+    /// Whether a todo should be shown under this filter.
+    fn matches(self, todo: &Todo) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Active => !todo.done.get(),
+            Filter::Completed => todo.done.get(),
+        }
+    }
+}
+
+/// A single item in a [`TodoList`].
+///
+/// Each field that can change is its own signal, so editing one todo only
+/// updates the parts of the page that show it.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Todo {
+    pub id: usize,
+    pub text: RwSignal<String>,
+    pub done: RwSignal<bool>,
+    /// Whether the todo is still being saved to the server. It's shown
+    /// straight away, but taken out again if saving it fails.
+    pub pending: RwSignal<bool>,
+}
+
+/// Pretends to save a new todo to a server.
+///
+/// Any todo whose text contains "fail" is rejected, so there's a way to
+/// see a failed save being rolled back.
+async fn save_todo(text: String) -> Result<(), String> {
+    sleep(Duration::from_millis(700)).await;
+    answer(&text)
+}
+
+/// What the pretend server answers a [`save_todo`] of `text` with, once
+/// it's done waiting.
+fn answer(text: &str) -> Result<(), String> {
+    if text.to_lowercase().contains("fail") {
+        Err(format!("Couldn't save \"{text}\""))
+    } else {
+        Ok(())
+    }
+}
+
+/// The form a [`Todo`] is saved in. Signals can't be serialized, so this
+/// holds their current values instead.
+#[derive(Clone, Serialize, Deserialize)]
+struct StoredTodo {
+    id: usize,
+    text: String,
+    done: bool,
+}
+
+/// The todos that `filter` shows, in order.
+///
+/// This is a memo, rather than a plain closure, so the filtering only reruns
+/// when the list, the filter, or a `done` flag it checked changes. Typing a
+/// new todo or editing a todo's text doesn't rerun it, which the log shows.
+///
+/// Checking a todo off under the "Active" filter removes it from this list,
+/// but since `<For>` is keyed by ID, the rest of the rows keep their DOM
+/// nodes.
+fn visible_todos(
+    cx: Scope,
+    todos: ReadSignal<Vec<Todo>>,
+    filter: Signal<Filter>,
+) -> Memo<Vec<Todo>> {
+    create_memo(cx, move |_| {
+        log!("visible todos recomputed");
+        let filter = filter.get();
+        todos.with(|todos| {
+            todos
+                .iter()
+                .filter(|todo| filter.matches(todo))
+                .copied()
+                .collect::<Vec<_>>()
+        })
+    })
+}
+
+/// A list of todos that can be added, checked off and deleted, and which
+/// is saved to `localStorage`.
+#[component]
+pub fn TodoList(cx: Scope) -> impl IntoView {
+    let (stored, set_stored) = use_local_storage(cx, STORAGE_KEY, Vec::<StoredTodo>::new());
+    let loaded = stored
+        .get_untracked()
+        .into_iter()
+        .map(|todo| Todo {
+            id: todo.id,
+            text: create_rw_signal(cx, todo.text),
+            done: create_rw_signal(cx, todo.done),
+            pending: create_rw_signal(cx, false),
+        })
+        .collect::<Vec<_>>();
+    let (todos, set_todos) = create_signal(cx, loaded);
+    let next_id = store_value(
+        cx,
+        todos.with_untracked(|todos| todos.iter().map(|todo| todo.id + 1).max().unwrap_or(0)),
+    );
+    let (new_text, set_new_text) = create_signal(cx, String::new());
+    let (filter, set_filter) = use_local_storage(cx, FILTER_STORAGE_KEY, Filter::All);
+    let toasts = use_context::<ToastContext>(cx);
+
+    let visible_todos = visible_todos(cx, todos, filter);
+
+    // This reads every todo's signals as well as the list, so it saves
+    // whenever a todo is added, removed, edited or checked off. Todos the
+    // server hasn't accepted yet are left out, so a reload can't keep one
+    // whose save then failed.
+    create_effect(cx, move |_| {
+        set_stored.set(todos.with(|todos| {
+            todos
+                .iter()
+                .filter(|todo| !todo.pending.get())
+                .map(|todo| StoredTodo {
+                    id: todo.id,
+                    text: todo.text.get(),
+                    done: todo.done.get(),
+                })
+                .collect()
+        }));
+    });
+
+    let add_todo = move |ev: KeyboardEvent| {
+        if ev.key() != "Enter" {
+            return;
+        }
+        let text = new_text.get_untracked().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        let id = next_id.get_value();
+        next_id.set_value(id + 1);
+        let todo = Todo {
+            id,
+            text: create_rw_signal(cx, text.clone()),
+            done: create_rw_signal(cx, false),
+            pending: create_rw_signal(cx, true),
+        };
+        // This is an "optimistic" update: the todo is added before the
+        // server has accepted it, so the list responds straight away. If
+        // the save fails, it's removed again.
+        set_todos.update(|todos| todos.push(todo));
+        set_new_text.set(String::new());
+        spawn_local(async move {
+            match save_todo(text).await {
+                Ok(()) => todo.pending.set(false),
+                Err(error) => {
+                    set_todos.update(|todos| todos.retain(|t| t.id != id));
+                    if let Some(toasts) = toasts {
+                        toasts.push_kind(error, ToastKind::Error);
+                    }
+                }
+            }
+        });
+    };
+
+    let remaining =
+        move || todos.with(|todos| todos.iter().filter(|todo| !todo.done.get()).count());
+
+    view! { cx,
+        <input
+            type="text"
+            placeholder="What needs to be done? (\"fail\" won't save)"
+            on:input=move |ev| set_new_text.set(event_target_value(&ev))
+            on:keydown=add_todo
+            prop:value=move || new_text.get()
+        />
+        <ul>
+            <For
+                each=move || visible_todos.get()
+                key=|todo| todo.id
+                view=move |cx, todo: Todo| {
+                    view! { cx,
+                        <li
+                            class:done=move || todo.done.get()
+                            class:pending=move || todo.pending.get()
+                        >
+                            <input
+                                type="checkbox"
+                                prop:checked=move || todo.done.get()
+                                on:change=move |_| todo.done.update(|done| *done = !*done)
+                            />
+                            {move || todo.text.get()}
+                            <Show when=move || todo.pending.get() fallback=|_| ()>
+                                <small>" (saving…)"</small>
+                            </Show>
+                            <button
+                                on:click=move |_| {
+                                    set_todos.update(|todos| todos.retain(|t| t.id != todo.id))
+                                }
+                            >
+                                "Delete"
+                            </button>
+                        </li>
+                    }
+                }
+            />
+        </ul>
+        <p>
+            {remaining} " items left "
+            {Filter::ALL
+                .into_iter()
+                .map(|option| view! { cx,
+                    <button
+                        on:click=move |_| set_filter.set(option)
+                        aria-pressed=move || (filter.get() == option).to_string()
+                    >
+                        {option.label()}
+                    </button>
+                })
+                .collect_view(cx)}
+        </p>
+    }
+}
+
+#[component]
+pub fn AppSix(cx: Scope) -> impl IntoView {
+    view! { cx,
+        <h1>"Todos"</h1>
+        <TodoList/>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, rc::Rc};
+
+    fn todo(cx: Scope, id: usize, text: &str) -> Todo {
+        Todo {
+            id,
+            text: create_rw_signal(cx, text.to_string()),
+            done: create_rw_signal(cx, false),
+            pending: create_rw_signal(cx, false),
+        }
+    }
+
+    fn ids(todos: Memo<Vec<Todo>>) -> Vec<usize> {
+        todos.with_untracked(|todos| todos.iter().map(|todo| todo.id).collect())
+    }
+
+    /// Runs `f` with two todos, the setter for the filter, and how many
+    /// times an effect reading [`visible_todos`] has run.
+    fn with_visible_todos(
+        f: impl FnOnce(Memo<Vec<Todo>>, [Todo; 2], WriteSignal<Filter>, Rc<Cell<usize>>) + 'static,
+    ) {
+        let runtime = create_runtime();
+        create_scope(runtime, |cx| {
+            let list = [todo(cx, 0, "Milk"), todo(cx, 1, "Eggs")];
+            let (todos, _) = create_signal(cx, list.to_vec());
+            let (filter, set_filter) = create_signal(cx, Filter::Active);
+            let visible = visible_todos(cx, todos, filter.into());
+            let runs = Rc::new(Cell::new(0));
+            create_effect(cx, {
+                let runs = Rc::clone(&runs);
+                move |_| {
+                    visible.track();
+                    runs.set(runs.get() + 1);
+                }
+            });
+            f(visible, list, set_filter, runs);
+        })
+        .dispose();
+        runtime.dispose();
+    }
+
+    #[test]
+    fn saving_fails_for_a_todo_containing_fail() {
+        assert_eq!(
+            answer("Fail the test"),
+            Err("Couldn't save \"Fail the test\"".to_string())
+        );
+        assert_eq!(answer("Buy milk"), Ok(()));
+    }
+
+    #[test]
+    fn editing_a_todos_text_doesnt_rerun_the_filtered_list() {
+        with_visible_todos(|_, [milk, _], _, runs| {
+            milk.text.set("Oat milk".to_string());
+            assert_eq!(runs.get(), 1);
+        });
+    }
+
+    #[test]
+    fn checking_a_todo_off_only_updates_the_list_if_it_leaves_it() {
+        with_visible_todos(|visible, [milk, eggs], set_filter, runs| {
+            milk.done.set(true);
+            assert_eq!(runs.get(), 2);
+            assert_eq!(ids(visible), [eggs.id]);
+
+            // Every todo is shown under "All", so checking one off reruns the
+            // filter but gives the same list.
+            set_filter.set(Filter::All);
+            assert_eq!(runs.get(), 3);
+            eggs.done.set(true);
+            assert_eq!(runs.get(), 3);
+            assert_eq!(ids(visible), [milk.id, eggs.id]);
+        });
+    }
+}
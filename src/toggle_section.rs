@@ -0,0 +1,35 @@
+use leptos::*;
+
+/// A button that shows or hides `children` underneath it.
+///
+/// The children are rendered with `<Show>`, so while they're hidden they
+/// aren't just invisible but removed entirely: their scope is disposed of,
+/// which stops any effects, intervals and so on inside them. They're
+/// created again from scratch the next time they're shown, so state inside
+/// them doesn't survive being hidden.
+#[component]
+pub fn ToggleSection(
+    cx: Scope,
+    #[prop(into)]
+    /// The text of the button.
+    title: String,
+    // `ChildrenFn` rather than `Children`, since `<Show>` may need to
+    // create the children more than once.
+    children: ChildrenFn,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    let children = store_value(cx, children);
+
+    view! { cx,
+        <button
+            aria-expanded=move || open.get().to_string()
+            on:click=move |_| set_open.update(|open| *open = !*open)
+        >
+            {move || if open.get() { "▾ " } else { "▸ " }}
+            {title}
+        </button>
+        <Show when=move || open.get() fallback=|_| ()>
+            {children.with_value(|children| children(cx))}
+        </Show>
+    }
+}
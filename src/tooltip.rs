@@ -0,0 +1,82 @@
+use crate::{events::use_event_listener, id_gen::next_id};
+use leptos::{html::Span, *};
+use wasm_bindgen::JsCast;
+
+/// Shows `text` next to `children` while they're hovered or focused.
+///
+/// Leptos 0.4 has no `<Portal>` to move the tooltip to the end of
+/// `<body>`, so it uses `position: fixed` instead, placed from where the
+/// trigger is on screen. That way a container with `overflow: hidden` can't
+/// clip it, but it has to be moved again whenever the page scrolls.
+#[component]
+pub fn Tooltip(
+    cx: Scope,
+    #[prop(into)]
+    /// What the tooltip says.
+    text: String,
+    children: Children,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    // Where the tooltip goes, as `(left, top)` in pixels from the top left
+    // of the window.
+    let (position, set_position) = create_signal(cx, (0.0, 0.0));
+    let trigger: NodeRef<Span> = create_node_ref(cx);
+    // Links the tooltip to the trigger, so screen readers read it out too.
+    let id = format!("tooltip-{}", next_id());
+
+    let place = move || {
+        if let Some(trigger) = trigger.get_untracked() {
+            let rect = trigger.get_bounding_client_rect();
+            set_position.set((rect.left(), rect.bottom() + 4.0));
+        }
+    };
+    let show = move || {
+        place();
+        set_open.set(true);
+    };
+    let hide = move || set_open.set(false);
+
+    if !cfg!(feature = "ssr") {
+        use_event_listener(cx, window().into(), "scroll", move |_| {
+            if open.get_untracked() {
+                place();
+            }
+        });
+        // Listening on the window rather than the trigger means Escape also
+        // works when the tooltip was opened by hovering, without focus.
+        use_event_listener(cx, window().into(), "keydown", move |ev| {
+            if ev
+                .dyn_ref::<web_sys::KeyboardEvent>()
+                .is_some_and(|ev| ev.key() == "Escape")
+            {
+                hide();
+            }
+        });
+    }
+
+    view! { cx,
+        <span
+            class="tooltip-trigger"
+            node_ref=trigger
+            aria-describedby=id.clone()
+            on:mouseenter=move |_| show()
+            on:mouseleave=move |_| hide()
+            on:focusin=move |_| show()
+            on:focusout=move |_| hide()
+        >
+            {children(cx)}
+        </span>
+        <span
+            id=id
+            role="tooltip"
+            class="tooltip"
+            class:hidden=move || !open.get()
+            style=move || {
+                let (left, top) = position.get();
+                format!("left: {left}px; top: {top}px")
+            }
+        >
+            {text}
+        </span>
+    }
+}
@@ -0,0 +1,83 @@
+use crate::progress::ProgressBar;
+use leptos::*;
+
+/// Shared between [`Wizard`] and each [`WizardStep`] inside it, in the same
+/// way as `Tabs` and `Tab`.
+#[derive(Clone, Copy)]
+struct WizardContext {
+    titles: RwSignal<Vec<String>>,
+    current: RwSignal<usize>,
+}
+
+/// Shows the [`WizardStep`]s inside it one at a time, with Back and Next
+/// buttons to move between them.
+#[component]
+pub fn Wizard(cx: Scope, children: Children) -> impl IntoView {
+    let titles = create_rw_signal(cx, Vec::new());
+    let current = create_rw_signal(cx, 0);
+    provide_context(cx, WizardContext { titles, current });
+
+    // The steps register themselves when they're created, so after this we
+    // know how many there are.
+    let children = children(cx);
+    let count = titles.with_untracked(Vec::len);
+
+    let is_first = move || current.get() == 0;
+    let is_last = move || current.get() + 1 >= count;
+    let step_title = move || titles.with(|titles| titles.get(current.get()).cloned());
+    let completed = Signal::derive(cx, move || current.get() as i32 + 1);
+
+    view! { cx,
+        <p>
+            {move || format!("Step {} of {count}: ", current.get() + 1)}
+            <strong>{step_title}</strong>
+        </p>
+        <ProgressBar
+            max=u16::try_from(count).unwrap_or(u16::MAX)
+            progress=completed
+            label="Wizard progress"
+        />
+        {children}
+        <button
+            type="button"
+            on:click=move |_| current.update(|step| *step = step.saturating_sub(1))
+            disabled=is_first
+        >
+            "Back"
+        </button>
+        <button
+            type="button"
+            on:click=move |_| current.update(|step| *step = (*step + 1).min(count.saturating_sub(1)))
+            disabled=is_last
+        >
+            "Next"
+        </button>
+    }
+}
+
+/// A single step in a [`Wizard`]. Its children are only shown while it's
+/// the current step, but stay mounted, so anything typed into them is kept
+/// when moving back and forth.
+#[component]
+pub fn WizardStep(
+    cx: Scope,
+    /// The name of this step, shown above it.
+    #[prop(into)]
+    title: String,
+    children: Children,
+) -> impl IntoView {
+    let WizardContext { titles, current } =
+        use_context(cx).expect("`WizardStep` to be used inside `Wizard`");
+    let index = titles
+        .try_update(|titles| {
+            titles.push(title);
+            titles.len() - 1
+        })
+        .unwrap_or_default();
+
+    view! { cx,
+        <div class:hidden=move || current.get() != index>
+            {children(cx)}
+        </div>
+    }
+}